@@ -0,0 +1,143 @@
+use crate::Result;
+
+// A pattern found in the haystack, start..end are byte positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    // index of the pattern in the list the matcher was built from
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+// Finds any number of byte patterns in one pass over the haystack, including
+// matches that overlap each other. The trie of the patterns is turned into a
+// complete automaton up front, so every byte of the haystack costs a single
+// table lookup. That makes it meant for a modest number of short patterns,
+// every state takes a full table of 256 transitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AhoCorasick {
+    next: Vec<[u32; 256]>,
+    // patterns that end in each state, longest first
+    outputs: Vec<Vec<usize>>,
+    lens: Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn new<P: AsRef<[u8]>>(patterns: &[P]) -> Result<Self> {
+        // state 0 is the root, no edge of the trie leads back to it, so 0
+        // means "no edge" until the automaton is completed
+        let mut next = vec![[0u32; 256]];
+        let mut outputs = vec![Vec::new()];
+        let mut lens = Vec::new();
+        for (id, pattern) in patterns.iter().enumerate() {
+            let pattern = pattern.as_ref();
+            if pattern.is_empty() {
+                return Err(format!("pattern {} is empty", id).into());
+            }
+            let mut state = 0;
+            for &b in pattern {
+                if next[state][b as usize] == 0 {
+                    next.push([0; 256]);
+                    outputs.push(Vec::new());
+                    next[state][b as usize] = (next.len() - 1) as u32;
+                }
+                state = next[state][b as usize] as usize;
+            }
+            outputs[state].push(id);
+            lens.push(pattern.len());
+        }
+
+        // Breadth first, so the failure state (the longest proper suffix that
+        // is in the trie as well) of every state is complete before it is
+        // needed. Missing edges go where the failure state would go.
+        let mut fail = vec![0usize; next.len()];
+        let mut queue = std::collections::VecDeque::new();
+        for b in 0..256 {
+            let child = next[0][b] as usize;
+            if child != 0 {
+                queue.push_back(child);
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            for b in 0..256 {
+                let child = next[state][b] as usize;
+                let target = next[fail[state]][b];
+                if child == 0 {
+                    next[state][b] = target;
+                    continue;
+                }
+                fail[child] = target as usize;
+                let inherited = outputs[target as usize].clone();
+                outputs[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+        Ok(AhoCorasick { next, outputs, lens })
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.lens.len()
+    }
+
+    // Every match in the haystack, ordered by where it ends. Matches that end
+    // at the same position come longest first.
+    pub fn find_overlapping<'a>(&'a self, haystack: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        let mut state = 0;
+        haystack.iter().enumerate().flat_map(move |(i, &b)| {
+            state = self.next[state][b as usize] as usize;
+            self.outputs[state]
+                .iter()
+                .map(move |&pattern| Match { pattern, start: i + 1 - self.lens[pattern], end: i + 1 })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn matches(patterns: &[&str], haystack: &str) -> Vec<(usize, usize, usize)> {
+        let ac = AhoCorasick::new(patterns).unwrap();
+        ac.find_overlapping(haystack.as_bytes()).map(|m| (m.pattern, m.start, m.end)).collect()
+    }
+
+    #[test]
+    fn overlapping_matches_are_all_reported() {
+        assert_eq!(matches(&["he", "she", "his", "hers"], "ushers"), [(1, 1, 4), (0, 2, 4), (3, 2, 6)]);
+        assert_eq!(matches(&["one", "two"], "twone"), [(1, 0, 3), (0, 2, 5)]);
+        assert_eq!(matches(&["aa"], "aaaa"), [(0, 0, 2), (0, 1, 3), (0, 2, 4)]);
+        assert_eq!(matches(&["fünf"], "fünfzig"), [(0, 0, 5)]);
+        assert!(matches(&["x"], "").is_empty());
+        assert!(AhoCorasick::new(&["a", ""]).is_err());
+    }
+
+    #[test]
+    fn agrees_with_a_naive_search() {
+        let mut rng = Rng::new(5);
+        for _ in 0..200 {
+            // words over a small alphabet, so that they overlap a lot
+            let word = |rng: &mut Rng, max: usize| -> String {
+                let len = rng.between(1, max);
+                (0..len).map(|_| (b'a' + rng.below(3) as u8) as char).collect()
+            };
+            let patterns: Vec<String> = (0..rng.between(1, 6)).map(|_| word(&mut rng, 4)).collect();
+            let haystack = word(&mut rng, 30);
+
+            let mut naive = Vec::new();
+            for end in 1..=haystack.len() {
+                let mut here: Vec<(usize, usize, usize)> = patterns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| haystack[..end].ends_with(p.as_str()))
+                    .map(|(id, p)| (id, end - p.len(), end))
+                    .collect();
+                here.sort_by_key(|&(id, start, _)| (start, id));
+                naive.extend(here);
+            }
+            let mut found = matches(&patterns.iter().map(|p| p.as_str()).collect::<Vec<_>>(), &haystack);
+            found.sort_by_key(|&(id, start, end)| (end, start, id));
+            assert_eq!(found, naive, "{:?} in {:?}", patterns, haystack);
+        }
+    }
+}
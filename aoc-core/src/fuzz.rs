@@ -0,0 +1,73 @@
+use crate::rng::Rng;
+use crate::solver::DynSolver;
+use crate::Result;
+
+// The fuzz target of a day: arbitrary bytes go into the parser, which may
+// reject them but must not panic. Where the day can print its parsed input,
+// printing has to be stable: what was printed parses again and prints the
+// same. Hangs are up to the caller to notice.
+pub fn check(solver: &dyn DynSolver, data: &[u8]) -> Result<()> {
+    let buf = String::from_utf8_lossy(data);
+    let Ok(parsed) = solver.parse_input(&buf) else {
+        return Ok(());
+    };
+    let Some(printed) = solver.print_input(&parsed) else {
+        return Ok(());
+    };
+    let reparsed = solver
+        .parse_input(&printed)
+        .map_err(|err| format!("the printed input doesn't parse: {}\n{}", err, printed))?;
+    let reprinted = solver.print_input(&reparsed).unwrap_or_default();
+    if reprinted != printed {
+        return Err(format!("printing is not stable:\n{}\nprints as\n{}", printed, reprinted).into());
+    }
+    Ok(())
+}
+
+// bytes that tend to matter to the parsers
+const INTERESTING: &[u8] = b"0123456789 \n\t:;,|=()-+#.?OSLJF7A\xff";
+
+// One to four random edits of data, sometimes mixing in a part of other.
+pub fn mutate(rng: &mut Rng, data: &[u8], other: &[u8]) -> Vec<u8> {
+    let mut out = data.to_vec();
+    for _ in 0..rng.between(1, 4) {
+        let at = rng.below(out.len() + 1);
+        let len = rng.between(1, 8).min(out.len() - at);
+        match rng.below(7) {
+            0 if at < out.len() => out[at] = rng.below(256) as u8,
+            1 => out.insert(at, *rng.pick(INTERESTING)),
+            2 => {
+                out.drain(at..at + len);
+            }
+            3 => {
+                let chunk = out[at..at + len].to_vec();
+                out.splice(at..at, chunk);
+            }
+            4 => out.truncate(at),
+            5 if !other.is_empty() => {
+                let from = rng.below(other.len());
+                let chunk = &other[from..other.len().min(from + rng.between(1, 64))];
+                out.splice(at..at + len, chunk.iter().copied());
+            }
+            _ => {
+                let number = *rng.pick(&["99999999999999999999", "-1", "0", "4294967296"]);
+                out.splice(at..at, number.bytes());
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutate_is_deterministic() {
+        let data = b"Game 1: 3 blue, 4 red\n";
+        let a = mutate(&mut Rng::new(3), data, b"Game 2: 1 red\n");
+        let b = mutate(&mut Rng::new(3), data, b"Game 2: 1 red\n");
+        assert_eq!(a, b);
+        assert!((0..100).any(|seed| mutate(&mut Rng::new(seed), data, b"") != data));
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+// Row-major 2D grid, positions are (row, column) pairs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// up, right, down, left
+pub const DIRECTIONS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1), (0, 1),
+    (1, -1), (1, 0), (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+
+    // cells in row-major order, cells.len() has to be a multiple of width
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells don't fill whole rows");
+        Self { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: (usize, usize)) -> bool {
+        pos.0 < self.height && pos.1 < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(self.contains(a) && self.contains(b), "position out of bounds");
+        self.cells.swap(a.0 * self.width + a.1, b.0 * self.width + b.1);
+    }
+
+    // the position one step into direction dir, if it's still on the grid
+    pub fn step(&self, pos: (usize, usize), dir: (isize, isize)) -> Option<(usize, usize)> {
+        let next = (pos.0.checked_add_signed(dir.0)?, pos.1.checked_add_signed(dir.1)?);
+        if self.contains(next) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS4.iter().filter_map(move |dir| self.step(pos, *dir))
+    }
+
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS8.iter().filter_map(move |dir| self.step(pos, *dir))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    // every cell together with its position
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() doesn't accept a size of 0, an empty grid has no rows either way
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(j < self.width, "column out of bounds");
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|j| self.col(j).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    // a quarter turn clockwise, the first column becomes the first row
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|j| self.col(j).rev().cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    // a quarter turn counterclockwise, the last column becomes the first row
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).rev().flat_map(|j| self.col(j).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        assert!(self.contains(pos), "position {:?} is outside of a {}x{} grid", pos, self.height, self.width);
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        assert!(self.contains(pos), "position {:?} is outside of a {}x{} grid", pos, self.height, self.width);
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+// one line per row, the way the grid looked in the input
+impl<T: Copy> Display for Grid<T>
+where
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", char::from(*cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn grid() -> Grid<u32> {
+        Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6])
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = grid();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.row(1), &[4, 5, 6]);
+        assert_eq!(g.col(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(g.rows().count(), 2);
+        assert_eq!(g[(1, 0)], 4);
+        assert_eq!(g.get((2, 0)), None);
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_grid() {
+        let g = grid();
+        let mut n4: Vec<_> = g.neighbors4((0, 0)).collect();
+        n4.sort();
+        assert_eq!(n4, vec![(0, 1), (1, 0)]);
+        assert_eq!(g.neighbors8((0, 1)).count(), 5);
+        assert_eq!(g.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let g = grid();
+        assert_eq!(g.transpose(), Grid::from_cells(2, vec![1, 4, 2, 5, 3, 6]));
+        assert_eq!(g.rotate_cw(), Grid::from_cells(2, vec![4, 1, 5, 2, 6, 3]));
+        assert_eq!(g.rotate_ccw(), Grid::from_cells(2, vec![3, 6, 2, 5, 1, 4]));
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+
+    #[test]
+    fn display_prints_one_line_per_row() {
+        let g = Grid::from_cells(2, vec!['#', '.', '.', '#']);
+        assert_eq!(g.to_string(), "#.\n.#");
+    }
+}
@@ -0,0 +1,17 @@
+pub mod aho_corasick;
+pub mod answer;
+pub mod fuzz;
+pub mod grid;
+pub mod log;
+pub mod math;
+pub mod parse;
+pub mod rng;
+pub mod session;
+pub mod solver;
+pub mod union_find;
+
+pub use answer::Answer;
+pub use solver::{DynSolver, Solver};
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// How much the solvers report about what they are doing. Everything goes to
+// stderr so that the answers on stdout stay machine readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    // -v, a few lines per part
+    Debug,
+    // -vv, intermediate state, can be a lot
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+// Log lines are prefixed with the module they come from, which for the
+// solvers is the name of the day.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!("[{}] {}", module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!("[{}] {}", module_path!(), format_args!($($arg)*));
+        }
+    };
+}
@@ -0,0 +1,52 @@
+use crate::Result;
+
+pub fn gcd(x: usize, y: usize) -> usize {
+    if y == 0 {
+        return x;
+    }
+    gcd(y, x % y)
+}
+
+// None if the result doesn't fit into usize
+pub fn lcm(x: usize, y: usize) -> Option<usize> {
+    let g = gcd(x, y);
+    if g == 0 {
+        return Some(0);
+    }
+    x.checked_mul(y / g)
+}
+
+// Integer types with overflow checked arithmetic. Answers are built from
+// numbers in the input, so a malformed input must not be able to make them
+// wrap around or panic.
+pub trait Checked: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+    fn add(self, other: Self) -> Option<Self>;
+    fn mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! checked {
+    ($($t:ty),*) => {
+        $(impl Checked for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn add(self, other: Self) -> Option<Self> { self.checked_add(other) }
+            fn mul(self, other: Self) -> Option<Self> { self.checked_mul(other) }
+        })*
+    };
+}
+
+checked!(i32, i64, u32, u64, usize);
+
+pub fn overflow<T>() -> crate::Error {
+    format!("the answer does not fit into {}", std::any::type_name::<T>()).into()
+}
+
+pub fn checked_sum<T: Checked>(xs: impl IntoIterator<Item = T>) -> Result<T> {
+    xs.into_iter().try_fold(T::ZERO, |acc, x| acc.add(x)).ok_or_else(overflow::<T>)
+}
+
+pub fn checked_product<T: Checked>(xs: impl IntoIterator<Item = T>) -> Result<T> {
+    xs.into_iter().try_fold(T::ONE, |acc, x| acc.mul(x)).ok_or_else(overflow::<T>)
+}
@@ -0,0 +1,391 @@
+use std::error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::{Error, Result};
+
+// Where and why parsing failed. line and column are 1-based, the column
+// counts characters, snippet is the whole offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(src: &str, pos: usize, expected: impl Display) -> Self {
+        let line_start = src[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = src[pos..].find('\n').map_or(src.len(), |i| pos + i);
+        Self {
+            line: src[..pos].matches('\n').count() + 1,
+            column: src[line_start..pos].chars().count() + 1,
+            snippet: src[line_start..line_end].trim_end_matches('\r').to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    // error at a cell of a grid that starts at the beginning of src, row and
+    // column count from 0 as in Grid
+    pub fn at_cell(src: &str, (row, col): (usize, usize), expected: impl Display) -> Self {
+        let line_start: usize = src.split_inclusive('\n').take(row).map(str::len).sum();
+        let pos = src[line_start..].char_indices().nth(col).map_or(src.len(), |(i, _)| line_start + i);
+        Self::new(src, pos, expected)
+    }
+}
+
+// line 1, column 19: expected one of "red", "green", "blue"
+//   |
+// 1 | Game 1: 3 blue, 4 purple
+//   |                   ^
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let found = self.snippet.chars().nth(self.column - 1).map_or_else(
+            || String::from("end of line"),
+            |c| format!("{:?}", c),
+        );
+        writeln!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, found)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet.replace('\t', " "))?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl error::Error for ParseError {}
+
+// Integer types the parser can read, the sign is only accepted for the
+// signed ones.
+pub trait Integer: FromStr + Copy {
+    const SIGNED: bool;
+}
+
+macro_rules! integer {
+    ($signed:literal: $($t:ty),*) => {
+        $(impl Integer for $t { const SIGNED: bool = $signed; })*
+    };
+}
+
+integer!(true: i8, i16, i32, i64, i128, isize);
+integer!(false: u8, u16, u32, u64, u128, usize);
+
+// Cursor over the puzzle input. All token level combinators (literal, number,
+// word, ...) skip leading spaces and tabs but never line breaks, so the line
+// structure of the input stays visible to the parsers built on top of them.
+//
+// A combinator that fails without having matched anything rewinds to where it
+// started. One that fails half way (a number too large for its type, a list
+// item after the separator) leaves the cursor where it is, which tells many()
+// that the error is real and not just the end of the list.
+pub struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+// Runs body on the whole input and makes sure nothing but whitespace is left.
+pub fn parse_all<'a, T>(src: &'a str, body: impl FnOnce(&mut Parser<'a>) -> Result<T>) -> Result<T> {
+    let mut p = Parser::new(src);
+    let value = body(&mut p)?;
+    p.eof()?;
+    Ok(value)
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    // 1-based line number of the current position
+    pub fn line(&self) -> usize {
+        self.src[..self.pos].matches('\n').count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    pub fn is_eof(&self) -> bool {
+        self.pos == self.src.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    // error at the current position, or rather at the next token since the
+    // combinators rewind to before the whitespace they skipped
+    pub fn error(&self, expected: impl Display) -> Error {
+        let ws = self.rest().len() - self.rest().trim_start_matches([' ', '\t']).len();
+        Box::new(ParseError::new(self.src, self.pos + ws, expected))
+    }
+
+    pub fn skip_ws(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    // single character mapped through f, e.g. a grid cell or a card
+    pub fn token<T>(&mut self, what: &str, f: impl Fn(char) -> Option<T>) -> Result<T> {
+        let c = self.peek().ok_or_else(|| self.error(what))?;
+        let value = f(c).ok_or_else(|| self.error(what))?;
+        self.pos += c.len_utf8();
+        Ok(value)
+    }
+
+    // the rest of the line as single character cells, e.g. a row of a grid
+    pub fn cells<T>(&mut self, what: &str, f: impl Fn(char) -> Option<T>) -> Result<Vec<T>> {
+        let mut row = Vec::new();
+        while !self.is_eof() && !self.rest().starts_with(['\n', '\r']) {
+            row.push(self.token(what, &f)?);
+        }
+        Ok(row)
+    }
+
+    pub fn literal(&mut self, lit: &str) -> Result<()> {
+        if self.eat(lit) {
+            Ok(())
+        } else {
+            Err(self.error(format_args!("{:?}", lit)))
+        }
+    }
+
+    // optional literal, consumes it only if present
+    pub fn eat(&mut self, lit: &str) -> bool {
+        let start = self.pos;
+        self.skip_ws();
+        if self.rest().starts_with(lit) {
+            self.pos += lit.len();
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    // first of the literals that matches, mapped to its value
+    pub fn choice<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T> {
+        for (lit, value) in options {
+            if self.eat(lit) {
+                return Ok(*value);
+            }
+        }
+        let names: Vec<String> = options.iter().map(|(lit, _)| format!("{:?}", lit)).collect();
+        Err(self.error(format_args!("one of {}", names.join(", "))))
+    }
+
+    pub fn number<T: Integer>(&mut self) -> Result<T> {
+        let before = self.pos;
+        self.skip_ws();
+        let start = self.pos;
+        if T::SIGNED && self.rest().starts_with('-') {
+            self.pos += 1;
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            self.pos = before;
+            return Err(self.error("a number"));
+        }
+        self.src[start..self.pos].parse::<T>().map_err(|_| {
+            let expected = format!("a number that fits into {}", std::any::type_name::<T>());
+            ParseError::new(self.src, start, expected).into()
+        })
+    }
+
+    // non-empty run of letters and digits
+    pub fn word(&mut self) -> Result<&'a str> {
+        let before = self.pos;
+        self.skip_ws();
+        let word = self.take_while(|c| c.is_alphanumeric());
+        if word.is_empty() {
+            self.pos = before;
+            return Err(self.error("a word"));
+        }
+        Ok(word)
+    }
+
+    // runs f and rewinds if it fails
+    pub fn attempt<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Option<T> {
+        let start = self.pos;
+        match f(self) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.pos = start;
+                None
+            }
+        }
+    }
+
+    // zero or more items, stops at the first one that doesn't match at all
+    pub fn many<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        loop {
+            let start = self.pos;
+            match item(self) {
+                Ok(value) => items.push(value),
+                Err(err) if self.pos != start => return Err(err),
+                Err(_) => return Ok(items),
+            }
+        }
+    }
+
+    // one or more items separated by sep
+    pub fn sep_by<T>(&mut self, sep: &str, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.eat(sep) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    // `label:` followed by body, which may start on the next line
+    pub fn section<T>(&mut self, label: &str, body: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.literal(label)?;
+        self.literal(":")?;
+        self.skip_ws();
+        if self.peek() == Some('\n') || self.rest().starts_with("\r\n") {
+            self.eol()?;
+        }
+        body(self)
+    }
+
+    // end of the current line, trailing spaces are fine
+    pub fn eol(&mut self) -> Result<()> {
+        self.skip_ws();
+        if self.is_eof() {
+            return Ok(());
+        }
+        self.eat("\r");
+        if self.rest().starts_with('\n') {
+            self.pos += 1;
+            return Ok(());
+        }
+        Err(self.error("end of line"))
+    }
+
+    pub fn skip_blank_lines(&mut self) {
+        while !self.is_eof() && self.at_blank_line() {
+            if self.eol().is_err() {
+                break;
+            }
+        }
+    }
+
+    pub fn at_blank_line(&self) -> bool {
+        let line = self.rest().split('\n').next().unwrap_or("");
+        line.trim().is_empty()
+    }
+
+    // rows of cells up to the next blank line or the end of input, every row
+    // has to be as wide as the first one
+    pub fn grid_with<T>(&mut self, what: &str, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        while !self.is_eof() && !self.at_blank_line() {
+            if cells.is_empty() {
+                cells = self.cells(what, &f)?;
+                width = cells.len();
+            } else {
+                for _ in 0..width {
+                    if self.is_eof() || self.rest().starts_with(['\n', '\r']) {
+                        return Err(self.error(what));
+                    }
+                    cells.push(self.token(what, &f)?);
+                }
+            }
+            self.eol()?;
+        }
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    pub fn grid<T: TryFrom<char>>(&mut self, what: &str) -> Result<Grid<T>> {
+        self.grid_with(what, |c| T::try_from(c).ok())
+    }
+
+    // one item per line up to the next blank line or the end of input
+    pub fn lines<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while !self.is_eof() && !self.at_blank_line() {
+            items.push(item(self)?);
+            self.eol()?;
+        }
+        Ok(items)
+    }
+
+    // only whitespace may be left
+    pub fn eof(&mut self) -> Result<()> {
+        self.take_while(|c| c.is_whitespace());
+        if self.is_eof() {
+            Ok(())
+        } else {
+            Err(self.error("end of input"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(err: Error) -> ParseError {
+        *err.downcast::<ParseError>().unwrap()
+    }
+
+    #[test]
+    fn error_points_at_the_offending_token() {
+        let err = parse_all("1 2\n3 x\n", |p| p.lines(|p| p.many(|p| p.number::<u32>()))).unwrap_err();
+        let err = parse_error(err);
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.snippet, "3 x");
+        assert_eq!(err.to_string(), "line 2, column 3: expected end of line, found 'x'\n  |\n2 | 3 x\n  |   ^");
+    }
+
+    #[test]
+    fn overflow_inside_many_is_not_swallowed() {
+        let err = parse_all("1 99999999999", |p| p.many(|p| p.number::<u32>())).unwrap_err();
+        let err = parse_error(err);
+        assert_eq!(err.column, 3);
+        assert_eq!(err.expected, "a number that fits into u32");
+    }
+
+    #[test]
+    fn signs_only_for_signed_numbers() {
+        assert_eq!(parse_all("-12", |p| p.number::<i32>()).unwrap(), -12);
+        assert!(parse_all("-12", |p| p.number::<u32>()).is_err());
+    }
+
+    #[test]
+    fn sections_and_separated_lists() {
+        let ns = parse_all("seeds:\n 1, 2 ,3\n", |p| p.section("seeds", |p| p.sep_by(",", |p| p.number::<u8>()))).unwrap();
+        assert_eq!(ns, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn grid_rows_must_be_as_wide_as_the_first() {
+        let grid: Grid<char> = parse_all("ab\ncd\n", |p| p.grid("a cell")).unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd");
+
+        let short = parse_error(parse_all("ab\nc\n", |p| p.grid::<char>("a cell")).unwrap_err());
+        assert_eq!((short.line, short.column), (2, 2));
+        let long = parse_error(parse_all("ab\ncde\n", |p| p.grid::<char>("a cell")).unwrap_err());
+        assert_eq!((long.line, long.column, long.expected.as_str()), (2, 3, "end of line"));
+    }
+
+    #[test]
+    fn errors_at_grid_cells() {
+        let err = ParseError::at_cell("ab
+çd
+", (1, 1), "a cell");
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "çd"));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::Result;
+
+// Interactive state a day builds from its parsed input for aoc repl, e.g. a
+// grid that can be tilted step by step or a walk through a map.
+pub trait Session {
+    // the commands run() knows, as (usage, what it does)
+    fn help(&self) -> Vec<(&'static str, &'static str)>;
+
+    // Runs the command with its arguments and returns what to show. None if
+    // the session doesn't know the command.
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String>>;
+}
+
+// the number argument of a command, or default if there is none
+pub fn count_arg(args: &[&str], default: usize) -> Result<usize> {
+    match args {
+        [] => Ok(default),
+        [n] => n.parse().map_err(|_| format!("not a number: {:?}", n).into()),
+        _ => Err("expected at most one number".into()),
+    }
+}
@@ -0,0 +1,122 @@
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::session::Session;
+use crate::Result;
+
+// A puzzle solution split into its stages. parse() turns the raw puzzle
+// input into whatever representation the day works on, both parts then
+// solve on that without touching the text again.
+pub trait Solver {
+    type Input;
+    type Answer: Into<Answer>;
+
+    fn parse(&self, buf: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer>;
+
+    // A slow but obviously correct way to solve the part, for days where
+    // part1/part2 take a clever shortcut. aoc diff compares the two.
+    fn reference(&self, _part: u32, _input: &Self::Input) -> Option<Result<Self::Answer>> {
+        None
+    }
+
+    // The parsed input written out the way the puzzle would have it, for the
+    // days that can. Used to check that parsing and printing round-trip.
+    fn print(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    // A random but valid puzzle input, size scales it in whatever way makes
+    // sense for the day (lines, rows, patterns, ...).
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Result<String> {
+        Err("there is no input generator for this day".into())
+    }
+
+    // How the answer of the part comes about, in a form meant for people
+    // chasing a wrong answer. Shown by aoc run --explain.
+    fn explain(&self, _part: u32, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    // Sets a day specific option, given as --set name=value on the command
+    // line.
+    fn configure(&mut self, name: &str, _value: &str) -> Result<()> {
+        Err(format!("this day has no option {:?}", name).into())
+    }
+
+    // State to explore interactively in aoc repl, for the days that have
+    // more to look at than the two answers.
+    fn session(&self, _input: &Self::Input) -> Option<Box<dyn Session>> {
+        None
+    }
+}
+
+pub type Parsed = Box<dyn Any + Send>;
+
+// Object safe view on a Solver so that days with different input and answer
+// types can live in the same registry.
+pub trait DynSolver: Send + Sync {
+    fn parse_input(&self, buf: &str) -> Result<Parsed>;
+    fn solve_part(&self, part: u32, input: &Parsed) -> Result<Answer>;
+    fn reference_part(&self, part: u32, input: &Parsed) -> Option<Result<Answer>>;
+    fn print_input(&self, input: &Parsed) -> Option<String>;
+    fn generate_input(&self, rng: &mut Rng, size: usize) -> Result<String>;
+    fn open_session(&self, input: &Parsed) -> Option<Box<dyn Session>>;
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()>;
+    fn explain_part(&self, part: u32, input: &Parsed) -> Option<String>;
+
+    fn solve(&self, part: u32, buf: &str) -> Result<Answer> {
+        let input = self.parse_input(buf)?;
+        self.solve_part(part, &input)
+    }
+}
+
+impl<S> DynSolver for S
+where
+    S: Solver + Send + Sync,
+    S::Input: Send + 'static,
+{
+    fn parse_input(&self, buf: &str) -> Result<Parsed> {
+        Ok(Box::new(self.parse(buf)?))
+    }
+
+    fn solve_part(&self, part: u32, input: &Parsed) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("input was parsed by a different solver")?;
+        match part {
+            1 => Ok(self.part1(input)?.into()),
+            2 => Ok(self.part2(input)?.into()),
+            _ => Err(format!("there is no part {}", part).into()),
+        }
+    }
+
+    fn reference_part(&self, part: u32, input: &Parsed) -> Option<Result<Answer>> {
+        let Some(input) = input.downcast_ref::<S::Input>() else {
+            return Some(Err("input was parsed by a different solver".into()));
+        };
+        self.reference(part, input).map(|answer| answer.map(Into::into))
+    }
+
+    fn print_input(&self, input: &Parsed) -> Option<String> {
+        self.print(input.downcast_ref::<S::Input>()?)
+    }
+
+    fn generate_input(&self, rng: &mut Rng, size: usize) -> Result<String> {
+        self.generate(rng, size)
+    }
+
+    fn open_session(&self, input: &Parsed) -> Option<Box<dyn Session>> {
+        self.session(input.downcast_ref::<S::Input>()?)
+    }
+
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()> {
+        self.configure(name, value)
+    }
+
+    fn explain_part(&self, part: u32, input: &Parsed) -> Option<String> {
+        self.explain(part, input.downcast_ref::<S::Input>()?)
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Answer, DynSolver};
+
+use crate::args::Args;
+use crate::input::{self, Source};
+use crate::output::{self, Format, Record};
+use crate::registry;
+use crate::timing::format_duration;
+
+const DEFAULT_JOBS: usize = 1;
+const DEFAULT_TIMEOUT_MS: u64 = 10000;
+
+// One part of one day to solve, input is None if the day has none.
+pub struct Task {
+    pub day: u32,
+    pub part: u32,
+    pub solver: fn() -> Box<dyn DynSolver>,
+    pub input: Option<Arc<String>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    Failed(String),
+    TimedOut,
+    NoInput,
+}
+
+#[derive(Debug)]
+pub struct Row {
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+// The part is solved on a thread of its own so that it can be given up on.
+// A solver that runs into the timeout can't be stopped, it keeps its thread
+// (and a core) until the process ends.
+fn solve_one(task: &Task, timeout: Duration) -> Row {
+    let row = |outcome, elapsed| Row { day: task.day, part: task.part, outcome, elapsed };
+    let Some(buf) = task.input.clone() else {
+        return row(Outcome::NoInput, Duration::ZERO);
+    };
+    let (solver, part) = (task.solver, task.part);
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = solver().solve(part, &buf);
+        let _ = tx.send((result, start.elapsed()));
+    });
+    match rx.recv_timeout(timeout) {
+        Ok((Ok(value), elapsed)) => row(Outcome::Solved(value), elapsed),
+        Ok((Err(err), elapsed)) => {
+            row(Outcome::Failed(err.to_string().lines().next().unwrap_or("").to_string()), elapsed)
+        }
+        Err(RecvTimeoutError::Timeout) => row(Outcome::TimedOut, timeout),
+        Err(RecvTimeoutError::Disconnected) => row(Outcome::Failed("the solver panicked".to_string()), Duration::ZERO),
+    }
+}
+
+// Solves the tasks on up to jobs threads, the rows come back ordered by day
+// and part.
+pub fn solve_all(tasks: &[Task], jobs: usize, timeout: Duration) -> Vec<Row> {
+    let next = AtomicUsize::new(0);
+    let rows = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                while let Some(task) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let row = solve_one(task, timeout);
+                    rows.lock().unwrap_or_else(|err| err.into_inner()).push(row);
+                }
+            });
+        }
+    });
+    let mut rows = rows.into_inner().unwrap_or_else(|err| err.into_inner());
+    rows.sort_by_key(|row| (row.day, row.part));
+    rows
+}
+
+fn table(rows: &[Row], wall: Duration, jobs: usize) -> String {
+    let mut out = format!("{:>3} {:>4}  {:>10}  answer\n", "day", "part", "time");
+    let mut counts = [0usize; 4];
+    for row in rows {
+        let note = match &row.outcome {
+            Outcome::Solved(value) => value.to_string(),
+            Outcome::Failed(err) => format!("error: {}", err),
+            Outcome::TimedOut => "timed out".to_string(),
+            Outcome::NoInput => "no input".to_string(),
+        };
+        let time = match row.outcome {
+            Outcome::NoInput => "-".to_string(),
+            Outcome::TimedOut => format!(">{}", format_duration(row.elapsed)),
+            _ => format_duration(row.elapsed),
+        };
+        counts[match row.outcome {
+            Outcome::Solved(_) => 0,
+            Outcome::Failed(_) => 1,
+            Outcome::TimedOut => 2,
+            Outcome::NoInput => 3,
+        }] += 1;
+        out += &format!("{:>3} {:>4}  {:>10}  {}\n", row.day, row.part, time, note);
+    }
+    let total: Duration = rows.iter().map(|row| row.elapsed).sum();
+    let [solved, failed, timed_out, missing] = counts;
+    out += &format!(
+        "{:<8}  {:>10}  {} solved, {} failed, {} timed out, {} without input\n",
+        "total",
+        format_duration(total),
+        solved,
+        failed,
+        timed_out,
+        missing
+    );
+    let threads = if jobs == 1 { "thread" } else { "threads" };
+    out += &format!("wall clock {} on {} {}\n", format_duration(wall), jobs, threads);
+    out
+}
+
+// Solves both parts of every registered day, each part within the timeout.
+// Text output is a table with the total time, JSON and CSV have the solved
+// parts only.
+pub fn run_all(args: &Args, format: Format) -> aoc_core::Result<()> {
+    if args.positional(0).is_some() {
+        return Err("--all doesn't take a day".into());
+    }
+    let jobs = args.option("jobs", DEFAULT_JOBS)?;
+    if jobs == 0 {
+        return Err("--jobs has to be at least 1".into());
+    }
+    let timeout = Duration::from_millis(args.option("timeout", DEFAULT_TIMEOUT_MS)?);
+
+    let mut tasks = Vec::new();
+    for entry in registry::DAYS {
+        let input = input::read(&Source::Default, entry.day).ok().map(Arc::new);
+        for part in 1..=2 {
+            tasks.push(Task { day: entry.day, part, solver: entry.solver, input: input.clone() });
+        }
+    }
+
+    let start = Instant::now();
+    let rows = solve_all(&tasks, jobs, timeout);
+    let wall = start.elapsed();
+    match format {
+        Format::Text => print!("{}", table(&rows, wall, jobs)),
+        _ => {
+            let records: Vec<Record> = rows
+                .iter()
+                .filter_map(|row| match &row.outcome {
+                    Outcome::Solved(value) => {
+                        Some(Record { day: row.day, part: row.part, value: value.clone(), elapsed: row.elapsed })
+                    }
+                    _ => None,
+                })
+                .collect();
+            print!("{}", output::render(format, &records));
+        }
+    }
+
+    let bad = rows.iter().filter(|row| matches!(row.outcome, Outcome::Failed(_) | Outcome::TimedOut)).count();
+    if bad > 0 {
+        return Err(format!("{} of {} parts failed or timed out", bad, rows.len()).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solver;
+
+    // part 1 echoes the number it is given, part 2 never finishes in time
+    #[derive(Default)]
+    struct Slow;
+
+    impl Solver for Slow {
+        type Input = u64;
+        type Answer = u64;
+
+        fn parse(&self, buf: &str) -> aoc_core::Result<u64> {
+            Ok(buf.trim().parse()?)
+        }
+
+        fn part1(&self, n: &u64) -> aoc_core::Result<u64> {
+            Ok(*n)
+        }
+
+        fn part2(&self, _: &u64) -> aoc_core::Result<u64> {
+            thread::sleep(Duration::from_secs(60));
+            Ok(0)
+        }
+    }
+
+    fn task(day: u32, part: u32, input: Option<&str>) -> Task {
+        Task { day, part, solver: || Box::<Slow>::default(), input: input.map(|s| Arc::new(s.to_string())) }
+    }
+
+    #[test]
+    fn runaway_solvers_time_out_without_holding_up_the_rest() {
+        let tasks = vec![
+            task(2, 2, Some("7")),
+            task(1, 1, Some("5")),
+            task(1, 2, Some("5")),
+            task(2, 1, Some("x")),
+            task(3, 1, None),
+        ];
+        let start = Instant::now();
+        let rows = solve_all(&tasks, 2, Duration::from_millis(200));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let outcomes: Vec<(u32, u32, &Outcome)> = rows.iter().map(|row| (row.day, row.part, &row.outcome)).collect();
+        assert_eq!(outcomes[0], (1, 1, &Outcome::Solved(Answer::Unsigned(5))));
+        assert_eq!(outcomes[1], (1, 2, &Outcome::TimedOut));
+        assert!(matches!(outcomes[2], (2, 1, Outcome::Failed(_))));
+        assert_eq!(outcomes[3], (2, 2, &Outcome::TimedOut));
+        assert_eq!(outcomes[4], (3, 1, &Outcome::NoInput));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const FILE_NAME: &str = "answers.txt";
+
+// A known answer, only valid for the input it was computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: String,
+    pub value: String,
+}
+
+// The answers file, one `<day> <part> <input hash> <value>` line per answer.
+// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+// FNV-1a, good enough to notice that an input file was swapped
+pub fn input_hash(buf: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in buf.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+impl Answers {
+    pub fn parse(buf: &str) -> aoc_core::Result<Self> {
+        let mut answers = Answers::default();
+        for (no, line) in buf.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, input_hash, value] = fields[..] else {
+                return Err(format!("line {}: expected day, part, input hash and value", no + 1).into());
+            };
+            let number = |field: &str, what: &str| {
+                field.parse::<u32>().map_err(|_| format!("line {}: {} is not a number: {:?}", no + 1, what, field))
+            };
+            answers.set(Entry {
+                day: number(day, "day")?,
+                part: number(part, "part")?,
+                input_hash: input_hash.to_string(),
+                value: value.to_string(),
+            });
+        }
+        Ok(answers)
+    }
+
+    // a missing file is the same as an empty one
+    pub fn load(path: &Path) -> aoc_core::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(buf) => Self::parse(&buf).map_err(|err| format!("{}: {}", path.display(), err).into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> aoc_core::Result<()> {
+        let mut buf = String::from("# day part input-hash answer\n");
+        for entry in &self.entries {
+            writeln!(buf, "{} {} {} {}", entry.day, entry.part, entry.input_hash, entry.value)?;
+        }
+        fs::write(path, buf).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.day == day && entry.part == part)
+    }
+
+    // replaces the answer for the same day and part, entries stay sorted
+    pub fn set(&mut self, entry: Entry) {
+        self.entries.retain(|e| (e.day, e.part) != (entry.day, entry.part));
+        self.entries.push(entry);
+        self.entries.sort_by_key(|e| (e.day, e.part));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_skips_comments_and_keeps_the_last_duplicate() {
+        let answers = Answers::parse("# header\n\n1 1 abc 142\n1 2 abc 281\n1 1 abc 143\n").unwrap();
+        assert_eq!(answers.get(1, 1).unwrap().value, "143");
+        assert_eq!(answers.get(1, 2).unwrap().input_hash, "abc");
+        assert!(answers.get(2, 1).is_none());
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        let err = Answers::parse("1 1 abc 142\n1 x abc 3\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: part is not a number: \"x\"");
+        assert!(Answers::parse("1 1 abc\n").is_err());
+    }
+
+    #[test]
+    fn input_hash_is_stable() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1abc2\n"), input_hash("1abc3\n"));
+    }
+}
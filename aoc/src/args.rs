@@ -0,0 +1,77 @@
+use std::collections::{HashMap, HashSet};
+
+// Minimal command line parsing: positional arguments plus `--name value`,
+// `--name=value` options and `--name` switches.
+pub struct Args {
+    positional: Vec<String>,
+    values: HashMap<String, Vec<String>>,
+    switches: HashSet<String>,
+}
+
+impl Args {
+    pub fn parse(raw: &[String], with_value: &[&str], switches: &[&str]) -> aoc_core::Result<Self> {
+        let mut args = Args { positional: Vec::new(), values: HashMap::new(), switches: HashSet::new() };
+        let mut it = raw.iter();
+        while let Some(arg) = it.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                args.positional.push(arg.clone());
+                continue;
+            };
+            let (name, inline) = match name.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (name, None),
+            };
+            if with_value.contains(&name) {
+                let value = match inline {
+                    Some(value) => value,
+                    None => it.next().ok_or_else(|| format!("option --{} needs a value", name))?.clone(),
+                };
+                args.values.entry(name.to_string()).or_default().push(value);
+            } else if switches.contains(&name) && inline.is_none() {
+                args.switches.insert(name.to_string());
+            } else {
+                return Err(format!("unknown option {}", arg).into());
+            }
+        }
+        Ok(args)
+    }
+
+    pub fn positional(&self, idx: usize) -> Option<&str> {
+        self.positional.get(idx).map(|s| s.as_str())
+    }
+
+    pub fn number(&self, idx: usize, what: &str) -> aoc_core::Result<u32> {
+        let arg = self.positional(idx).ok_or_else(|| format!("missing {}", what))?;
+        arg.parse::<u32>().map_err(|_| format!("{} is not a number: {:?}", what, arg).into())
+    }
+
+    // optional positional number
+    pub fn maybe_number(&self, idx: usize, what: &str) -> aoc_core::Result<Option<u32>> {
+        match self.positional(idx) {
+            None => Ok(None),
+            Some(_) => self.number(idx, what).map(Some),
+        }
+    }
+
+    // value of an option parsed as a number, default when it isn't given
+    pub fn option<T: std::str::FromStr>(&self, name: &str, default: T) -> aoc_core::Result<T> {
+        match self.value(name) {
+            None => Ok(default),
+            Some(value) => value.parse().map_err(|_| format!("--{} is not a number: {:?}", name, value).into()),
+        }
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.contains(name)
+    }
+
+    // every occurrence of an option, in order
+    pub fn values(&self, name: &str) -> &[String] {
+        self.values.get(name).map_or(&[], |vs| vs.as_slice())
+    }
+
+    // last occurrence wins if an option is given more than once
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).and_then(|vs| vs.last()).map(|s| s.as_str())
+    }
+}
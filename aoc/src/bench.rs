@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::args::Args;
+use crate::input::{self, Source};
+use crate::registry;
+use crate::timing::{format_duration, Stats};
+
+const DEFAULT_RUNS: usize = 10;
+// a stage counts as regressed if its median is this many percent slower
+// than in the baseline
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+// median per (day, stage) of an earlier run
+type Baseline = HashMap<(u32, String), Duration>;
+
+// one `<day> <stage> <median in ns>` line per stage
+fn parse_baseline(buf: &str) -> aoc_core::Result<Baseline> {
+    let mut baseline = Baseline::new();
+    for (no, line) in buf.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let parsed = match fields[..] {
+            [day, stage, nanos] => day.parse::<u32>().ok().zip(nanos.parse::<u64>().ok()).map(|(d, n)| (d, stage, n)),
+            _ => None,
+        };
+        let (day, stage, nanos) =
+            parsed.ok_or_else(|| format!("line {}: expected day, stage and median in ns", no + 1))?;
+        baseline.insert((day, stage.to_string()), Duration::from_nanos(nanos));
+    }
+    Ok(baseline)
+}
+
+fn load_baseline(path: &Path) -> aoc_core::Result<Baseline> {
+    let buf = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    parse_baseline(&buf).map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+fn save_baseline(path: &Path, results: &[(u32, &str, Stats)]) -> aoc_core::Result<()> {
+    let mut buf = String::from("# day stage median-ns\n");
+    for (day, stage, stats) in results {
+        writeln!(buf, "{} {} {}", day, stage, stats.median.as_nanos())?;
+    }
+    fs::write(path, buf).map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+// runs f repeatedly, returns the result of the last run
+fn measure<T>(runs: usize, mut f: impl FnMut() -> aoc_core::Result<T>) -> aoc_core::Result<(T, Stats)> {
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        last = Some(value);
+    }
+    let stats = Stats::of(&samples).expect("at least one run");
+    Ok((last.expect("at least one run"), stats))
+}
+
+// Times parse, part1 and part2 of every registered solver (or just the one
+// for the given day) on its default input.
+pub fn bench(args: &Args) -> aoc_core::Result<()> {
+    let only = args.maybe_number(0, "day")?;
+    let runs: usize = args.option("runs", DEFAULT_RUNS)?;
+    if runs == 0 {
+        return Err("--runs has to be at least 1".into());
+    }
+    let threshold: f64 = args.option("threshold", DEFAULT_THRESHOLD)?;
+    let baseline = args.value("baseline").map(|path| load_baseline(Path::new(path))).transpose()?;
+
+    let days = registry::select(only)?;
+
+    let mut results: Vec<(u32, &str, Stats)> = Vec::new();
+    let mut errors = 0;
+    let mut regressions = 0;
+    println!("{:>3} {:<5} {:>10} {:>10} {:>10}", "day", "stage", "min", "median", "max");
+    for entry in days {
+        let Ok(buf) = input::read(&Source::Default, entry.day) else {
+            println!("{:>3} no input", entry.day);
+            continue;
+        };
+        let solver = (entry.solver)();
+
+        let mut stages: Vec<(&str, aoc_core::Result<Stats>)> = Vec::new();
+        match measure(runs, || solver.parse_input(&buf)) {
+            Ok((parsed, stats)) => {
+                stages.push(("parse", Ok(stats)));
+                for (part, stage) in [(1, "part1"), (2, "part2")] {
+                    let timed = measure(runs, || solver.solve_part(part, &parsed));
+                    stages.push((stage, timed.map(|(_, stats)| stats)));
+                }
+            }
+            Err(err) => stages.push(("parse", Err(err))),
+        }
+
+        for (stage, timed) in stages {
+            let stats = match timed {
+                Ok(stats) => stats,
+                Err(err) => {
+                    errors += 1;
+                    let msg = err.to_string();
+                    println!("{:>3} {:<5} error: {}", entry.day, stage, msg.lines().next().unwrap_or(""));
+                    continue;
+                }
+            };
+            let mut line = format!(
+                "{:>3} {:<5} {:>10} {:>10} {:>10}",
+                entry.day,
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+            let before = baseline.as_ref().and_then(|b| b.get(&(entry.day, stage.to_string())));
+            if let Some(before) = before.filter(|before| !before.is_zero()) {
+                let change = (stats.median.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+                write!(line, "  {:+.1}% vs {}", change, format_duration(*before))?;
+                if change > threshold {
+                    regressions += 1;
+                    line.push_str("  REGRESSED");
+                }
+            }
+            println!("{}", line);
+            results.push((entry.day, stage, stats));
+        }
+    }
+
+    if let Some(path) = args.value("save") {
+        save_baseline(Path::new(path), &results)?;
+        println!("saved baseline of {} stages to {}", results.len(), path);
+    }
+    if errors > 0 {
+        return Err(format!("{} stages failed", errors).into());
+    }
+    if regressions > 0 {
+        return Err(format!("{} stages regressed by more than {}%", regressions, threshold).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_baseline_reads_medians() {
+        let baseline = parse_baseline("# day stage median-ns\n\n4 part2 1500\n4 parse 20\n").unwrap();
+        assert_eq!(baseline[&(4, "part2".to_string())], Duration::from_nanos(1500));
+        assert_eq!(baseline.len(), 2);
+        let err = parse_baseline("4 part2 1500\n4 part1 fast\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected day, stage and median in ns");
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, DynSolver};
+
+use crate::args::Args;
+use crate::registry;
+
+const DEFAULT_SEEDS: u64 = 100;
+const DEFAULT_SIZE: usize = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    NoReference,
+    Agree,
+    // the first seed the fast solution and the reference disagree on
+    Diverged { seed: u64, fast: String, reference: String },
+}
+
+// errors agree with each other, whatever they say
+fn outcome(result: &aoc_core::Result<Answer>) -> String {
+    match result {
+        Ok(value) => value.to_string(),
+        Err(err) => format!("error ({})", err.to_string().lines().next().unwrap_or("")),
+    }
+}
+
+pub fn compare(solver: &dyn DynSolver, part: u32, seeds: u64, size: usize) -> aoc_core::Result<Outcome> {
+    for seed in 0..seeds {
+        let buf = solver.generate_input(&mut Rng::new(seed), size)?;
+        let parsed = solver
+            .parse_input(&buf)
+            .map_err(|err| format!("generated input for seed {} doesn't parse: {}", seed, err))?;
+        let Some(reference) = solver.reference_part(part, &parsed) else {
+            return Ok(Outcome::NoReference);
+        };
+        let fast = solver.solve_part(part, &parsed);
+        let agree = match (&fast, &reference) {
+            (Ok(a), Ok(b)) => a == b,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        if !agree {
+            return Ok(Outcome::Diverged { seed, fast: outcome(&fast), reference: outcome(&reference) });
+        }
+    }
+    Ok(Outcome::Agree)
+}
+
+// Runs the fast solutions and the references of every day that has them
+// against generated inputs and reports the first seed they disagree on.
+pub fn diff(args: &Args) -> aoc_core::Result<()> {
+    let only = args.maybe_number(0, "day")?;
+    let seeds = args.option("seeds", DEFAULT_SEEDS)?;
+    let size = args.option("size", DEFAULT_SIZE)?;
+
+    let days = registry::select(only)?;
+
+    let mut checked = 0;
+    let mut diverged = 0;
+    for entry in days {
+        let solver = (entry.solver)();
+        for part in 1..=2 {
+            match compare(solver.as_ref(), part, seeds, size)? {
+                Outcome::NoReference => continue,
+                Outcome::Agree => println!("day {} part {}: {} inputs agree", entry.day, part, seeds),
+                Outcome::Diverged { seed, fast, reference } => {
+                    diverged += 1;
+                    println!(
+                        "day {} part {}: seed {} differs, fast solution {} but reference {}",
+                        entry.day, part, seed, fast, reference
+                    );
+                    println!("  reproduce with: aoc gen {} --seed {} --size {}", entry.day, seed, size);
+                }
+            }
+            checked += 1;
+        }
+    }
+
+    if checked == 0 {
+        return Err("no reference solutions to compare against".into());
+    }
+    if diverged > 0 {
+        return Err(format!("{} of {} parts diverged", diverged, checked).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_agree_on_small_inputs() {
+        for entry in registry::DAYS {
+            let solver = (entry.solver)();
+            for part in 1..=2 {
+                let outcome = compare(solver.as_ref(), part, 10, 6).unwrap();
+                assert!(!matches!(outcome, Outcome::Diverged { .. }), "day {}: {:?}", entry.day, outcome);
+            }
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::args::Args;
+use crate::input;
+
+// environment variables the fetcher is configured with
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2023;
+// the site asks for no more than a request every few seconds
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+// unix time of the last request in nanoseconds, kept next to the cached inputs
+const STAMP_FILE: &str = ".last-fetch";
+const USER_AGENT: &str = "aoc-2023-runner (std only)";
+
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+    pub interval: Duration,
+}
+
+struct Response {
+    status: u32,
+    body: String,
+}
+
+// http://host[:port]/path, split into the address to connect to, the host
+// header and the path
+fn split_url(url: &str) -> aoc_core::Result<(String, String, String)> {
+    let rest = url.strip_prefix("http://").ok_or_else(|| format!("not an http:// url: {}", url))?;
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let addr = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+    Ok((addr, host.to_string(), path.to_string()))
+}
+
+fn dechunk(mut body: &[u8]) -> aoc_core::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    loop {
+        let end = body.windows(2).position(|w| w == b"\r\n").ok_or("truncated chunked body")?;
+        let size = std::str::from_utf8(&body[..end])?.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| format!("bad chunk size {:?}", size))?;
+        body = &body[end + 2..];
+        if size == 0 {
+            return Ok(out);
+        }
+        if body.len() < size {
+            return Err("truncated chunked body".into());
+        }
+        out.extend_from_slice(&body[..size]);
+        body = body[size..].strip_prefix(b"\r\n").ok_or("truncated chunked body")?;
+    }
+}
+
+// A plain HTTP/1.1 GET, enough for a local stub of the site.
+fn get_http(url: &str, session: &str) -> aoc_core::Result<Response> {
+    let (addr, host, path) = split_url(url)?;
+    let mut stream = TcpStream::connect(&addr).map_err(|err| format!("{}: {}", addr, err))?;
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n\r\n",
+        path, host, USER_AGENT, session
+    )?;
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+
+    let head_end = raw.windows(4).position(|w| w == b"\r\n\r\n").ok_or("no complete response from the server")?;
+    let head = String::from_utf8_lossy(&raw[..head_end]);
+    let mut lines = head.lines();
+    let status_line = lines.next().unwrap_or("");
+    let status = status_line
+        .split(' ')
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| format!("bad status line {:?}", status_line))?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+    let body = &raw[head_end + 4..];
+    let body = if chunked { dechunk(body)? } else { body.to_vec() };
+    Ok(Response { status, body: String::from_utf8(body)? })
+}
+
+// std has no TLS, the real site is reached through curl. The cookie goes in
+// on stdin, on the command line any local user could read it.
+fn get_https(url: &str, session: &str) -> aoc_core::Result<Response> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--user-agent", USER_AGENT, "--write-out", "\n%{http_code}"])
+        .args(["--header", "@-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("can't run curl for https: {}", err))?;
+    let mut stdin = child.stdin.take().ok_or("no stdin for curl")?;
+    writeln!(stdin, "Cookie: session={}", session)?;
+    drop(stdin);
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim()).into());
+    }
+    let out = String::from_utf8(output.stdout)?;
+    let (body, status) = out.rsplit_once('\n').ok_or("no status from curl")?;
+    let status = status.parse().map_err(|_| format!("bad status from curl: {:?}", status))?;
+    Ok(Response { status, body: body.to_string() })
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+// The session ends up in a request header, anything but a plain token could
+// add headers of its own. The value itself is a secret and stays out of the
+// error.
+fn check_session(session: &str) -> aoc_core::Result<&str> {
+    if session.is_empty() || session.chars().any(|c| !c.is_ascii_graphic() || c == ';') {
+        let err = format!("{} has to be the bare session cookie, without spaces, control characters or ;", SESSION_VAR);
+        return Err(err.into());
+    }
+    Ok(session)
+}
+
+impl Fetcher {
+    // Configured from the environment, caching into the inputs directory.
+    pub fn from_env() -> aoc_core::Result<Self> {
+        let session =
+            env::var(SESSION_VAR).map_err(|_| format!("set {} to the session cookie of the site", SESSION_VAR))?;
+        Ok(Fetcher {
+            base_url: env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: check_session(session.trim())?.to_string(),
+            cache_dir: input::default_dir().ok_or("no inputs directory found")?,
+            interval: MIN_INTERVAL,
+        })
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(input::file_name(day))
+    }
+
+    // an empty file is the slot aoc new leaves, not a cached input
+    pub fn is_cached(&self, day: u32) -> bool {
+        fs::metadata(self.path(day)).is_ok_and(|meta| meta.len() > 0)
+    }
+
+    // Waits until the last request is at least the interval ago, as recorded
+    // in the stamp file so that it holds across runs.
+    fn wait_for_turn(&self) -> aoc_core::Result<()> {
+        let stamp = self.cache_dir.join(STAMP_FILE);
+        let last = fs::read_to_string(&stamp).ok().and_then(|s| s.trim().parse::<u64>().ok());
+        if let Some(last) = last {
+            let next = Duration::from_nanos(last) + self.interval;
+            if let Some(wait) = next.checked_sub(now()) {
+                aoc_core::debug!("waiting {:?} before the next request", wait);
+                thread::sleep(wait);
+            }
+        }
+        fs::write(&stamp, now().as_nanos().to_string())?;
+        Ok(())
+    }
+
+    // Returns the path of the input of the day, downloading it unless it
+    // is already cached.
+    pub fn fetch(&self, day: u32) -> aoc_core::Result<PathBuf> {
+        let path = self.path(day);
+        if self.is_cached(day) {
+            return Ok(path);
+        }
+        let url = format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), YEAR, day);
+        self.wait_for_turn()?;
+        aoc_core::debug!("GET {}", url);
+        let response = if url.starts_with("https://") {
+            get_https(&url, &self.session)?
+        } else {
+            get_http(&url, &self.session)?
+        };
+        match response.status {
+            200 if !response.body.is_empty() => {}
+            200 => return Err(format!("{} sent an empty input", url).into()),
+            400 | 401 | 403 => return Err(format!("{} rejected the session cookie ({})", url, response.status).into()),
+            404 => return Err(format!("day {} isn't unlocked yet", day).into()),
+            status => return Err(format!("{} answered with status {}", url, status).into()),
+        }
+        write_atomically(&path, &response.body)?;
+        Ok(path)
+    }
+}
+
+// a half written file would look like a cached input
+fn write_atomically(path: &Path, data: &str) -> aoc_core::Result<()> {
+    let tmp = path.with_extension("part");
+    fs::write(&tmp, data)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+// Downloads the input of a day into the inputs directory, unless it is there
+// already.
+pub fn fetch(args: &Args) -> aoc_core::Result<()> {
+    let day = args.number(0, "day")?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}", day).into());
+    }
+    let fetcher = Fetcher::from_env()?;
+    if fetcher.is_cached(day) {
+        println!("{} is cached", fetcher.path(day).display());
+        return Ok(());
+    }
+    let path = fetcher.fetch(day)?;
+    println!("downloaded {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    // Serves /2023/day/<n>/input as "input <n>" to the session "good" and
+    // counts the requests it gets. Day 2 is sent chunked.
+    fn stub_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut head = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    head.push(line);
+                }
+                let day = head[0].split('/').nth(3).unwrap_or("").to_string();
+                let response = if !head.iter().any(|line| line == "Cookie: session=good") {
+                    "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n".to_string()
+                } else if day == "2" {
+                    let chunks = "4\r\ninpu\r\n3\r\nt 2\r\n0\r\n\r\n";
+                    format!("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n{}", chunks)
+                } else {
+                    format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\ninput {}", 6 + day.len(), day)
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn fetcher(name: &str, base_url: &str, session: &str, interval: Duration) -> Fetcher {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        fs::create_dir_all(&cache_dir).unwrap();
+        Fetcher { base_url: base_url.to_string(), session: session.to_string(), cache_dir, interval }
+    }
+
+    #[test]
+    fn inputs_are_downloaded_once() {
+        let (url, requests) = stub_server();
+        let fetcher = fetcher("once", &url, "good", Duration::ZERO);
+        fs::write(fetcher.path(1), "").unwrap();
+        let path = fetcher.fetch(1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input 1");
+        assert_eq!(fetcher.fetch(1).unwrap(), path);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(fs::read_to_string(fetcher.fetch(2).unwrap()).unwrap(), "input 2");
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn a_rejected_session_caches_nothing() {
+        let (url, _) = stub_server();
+        let fetcher = fetcher("rejected", &url, "bad", Duration::ZERO);
+        let err = fetcher.fetch(3).unwrap_err();
+        assert!(err.to_string().contains("rejected the session cookie"), "{}", err);
+        assert!(!fetcher.is_cached(3));
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn sessions_must_be_plain_tokens() {
+        assert_eq!(check_session("53616c7465").unwrap(), "53616c7465");
+        for bad in ["", "abc\r\nX-Injected: 1", "abc; other=1", "a b", "abc\t"] {
+            assert!(check_session(bad).is_err(), "{:?}", bad);
+        }
+        assert!(!check_session("secret\n").unwrap_err().to_string().contains("secret"));
+    }
+
+    #[test]
+    fn requests_are_spaced_out() {
+        let (url, requests) = stub_server();
+        let fetcher = fetcher("spaced", &url, "good", Duration::from_millis(300));
+        let start = Instant::now();
+        for day in 4..=6 {
+            fetcher.fetch(day).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(600));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::fuzz::{check, mutate};
+use aoc_core::rng::Rng;
+
+use crate::args::Args;
+use crate::registry;
+
+const DEFAULT_CASES: u64 = 10000;
+const DEFAULT_TIMEOUT_MS: u64 = 1000;
+
+// An input the parser of a day doesn't cope with.
+#[derive(Debug)]
+pub struct Failure {
+    pub case: u64,
+    pub what: String,
+    pub input: Vec<u8>,
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// Feeds mutated generated inputs to the day's fuzz target. The cases run on
+// a worker thread so that a hang shows up as a timeout. A hanging worker
+// can't be stopped, it is left behind until the process ends.
+pub fn fuzz_day(day: &registry::Day, cases: u64, seed: u64, timeout: Duration) -> aoc_core::Result<Option<Failure>> {
+    let solver = (day.solver)();
+    let mut rng = Rng::new(seed);
+    let mut corpus: Vec<Vec<u8>> = vec![Vec::new()];
+    for size in 1..=6 {
+        corpus.push(solver.generate_input(&mut rng, size)?.into_bytes());
+    }
+
+    let (to_worker, cases_rx) = mpsc::channel::<Vec<u8>>();
+    let (results_tx, from_worker) = mpsc::channel::<Result<(), String>>();
+    thread::spawn(move || {
+        for data in cases_rx {
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| check(solver.as_ref(), &data))) {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(err)) => Err(err.to_string()),
+                Err(payload) => Err(format!("panic: {}", panic_message(payload.as_ref()))),
+            };
+            if results_tx.send(result).is_err() {
+                break;
+            }
+        }
+    });
+
+    for case in 0..cases {
+        let (a, b) = (rng.below(corpus.len()), rng.below(corpus.len()));
+        let input = mutate(&mut rng, &corpus[a], &corpus[b]);
+        to_worker.send(input.clone()).map_err(|_| "the fuzz worker is gone")?;
+        let what = match from_worker.recv_timeout(timeout) {
+            Ok(Ok(())) => continue,
+            Ok(Err(what)) => what,
+            Err(_) => format!("no result after {:?}", timeout),
+        };
+        return Ok(Some(Failure { case, what, input }));
+    }
+    Ok(None)
+}
+
+// Fuzzes the parsers of every day (or just the given one). The first input
+// a parser fails on is written to fuzz-<day>.txt.
+pub fn fuzz(args: &Args) -> aoc_core::Result<()> {
+    let only = args.maybe_number(0, "day")?;
+    let cases = args.option("cases", DEFAULT_CASES)?;
+    let seed = args.option("seed", 0)?;
+    let timeout = Duration::from_millis(args.option("timeout", DEFAULT_TIMEOUT_MS)?);
+
+    let days = registry::select(only)?;
+
+    // panics are reported as failures, the default hook would only add noise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut failed = Vec::new();
+    for entry in days {
+        match fuzz_day(entry, cases, seed, timeout) {
+            Ok(None) => println!("day {}: {} cases, no failures", entry.day, cases),
+            Ok(Some(failure)) => {
+                let path = format!("fuzz-{}.txt", entry.day);
+                println!("day {}: case {} failed: {}", entry.day, failure.case, failure.what);
+                match fs::write(&path, &failure.input) {
+                    Ok(()) => println!("  input written to {}", path),
+                    Err(err) => println!("  can't write the input to {}: {}", path, err),
+                }
+                failed.push(entry.day);
+            }
+            Err(err) => {
+                panic::set_hook(hook);
+                return Err(err);
+            }
+        }
+    }
+    panic::set_hook(hook);
+
+    if !failed.is_empty() {
+        let days: Vec<String> = failed.iter().map(|day| day.to_string()).collect();
+        return Err(format!("fuzzing failed for day {}", days.join(", ")).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsers_survive_a_few_hundred_cases() {
+        for entry in registry::DAYS {
+            if let Some(failure) = fuzz_day(entry, 300, 1, Duration::from_secs(10)).unwrap() {
+                panic!("day {}: {}\n{}", entry.day, failure.what, String::from_utf8_lossy(&failure.input));
+            }
+        }
+    }
+
+    // generated inputs are written the way the printers write them
+    #[test]
+    fn printers_reproduce_generated_inputs() {
+        for entry in registry::DAYS {
+            let solver = (entry.solver)();
+            let buf = solver.generate_input(&mut Rng::new(2), 5).unwrap();
+            if let Some(printed) = solver.print_input(&solver.parse_input(&buf).unwrap()) {
+                assert_eq!(printed, buf, "day {}", entry.day);
+            }
+        }
+    }
+}
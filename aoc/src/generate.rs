@@ -0,0 +1,46 @@
+use std::fs;
+use std::io::{self, Write};
+
+use aoc_core::rng::Rng;
+
+use crate::args::Args;
+use crate::registry;
+
+const DEFAULT_SIZE: usize = 100;
+
+// Writes a random puzzle input for the day, the same seed and size always
+// give the same input.
+pub fn generate(args: &Args) -> aoc_core::Result<()> {
+    let day = args.number(0, "day")?;
+    let seed = args.option("seed", 0)?;
+    let size = args.option("size", DEFAULT_SIZE)?;
+
+    let entry = registry::find(day).ok_or_else(|| format!("no solver registered for day {}", day))?;
+    let buf = (entry.solver)().generate_input(&mut Rng::new(seed), size)?;
+    match args.value("output") {
+        Some(path) => fs::write(path, buf).map_err(|err| format!("{}: {}", path, err).into()),
+        None => io::stdout().write_all(buf.as_bytes()).map_err(|err| err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every generator has to produce inputs that the day can solve
+    #[test]
+    fn generated_inputs_solve() {
+        for entry in registry::DAYS {
+            let solver = (entry.solver)();
+            for seed in 0..3 {
+                let buf = solver.generate_input(&mut Rng::new(seed), 20).unwrap();
+                assert_eq!(buf, solver.generate_input(&mut Rng::new(seed), 20).unwrap());
+                for part in 1..=2 {
+                    if let Err(err) = solver.solve(part, &buf) {
+                        panic!("day {} part {} seed {}: {}\n{}", entry.day, part, seed, err, buf);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// environment variable that overrides the inputs directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Clone)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    // inputs/day<N>.txt, see default_path
+    Default,
+}
+
+impl Source {
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Default,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+pub fn file_name(day: u32) -> String {
+    format!("day{}.txt", day)
+}
+
+// Candidate inputs directories in lookup order: $AOC_INPUT_DIR if set,
+// otherwise `inputs/` in the current directory or any of its parents and
+// finally the one in the workspace this binary was built from.
+fn input_dirs() -> Vec<PathBuf> {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return vec![PathBuf::from(dir)];
+    }
+    let mut dirs = Vec::new();
+    if let Ok(cwd) = env::current_dir() {
+        dirs.extend(cwd.ancestors().map(|dir| dir.join("inputs")));
+    }
+    dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs"));
+    dirs
+}
+
+// the inputs directory that is actually there, if any
+pub fn default_dir() -> Option<PathBuf> {
+    input_dirs().into_iter().find(|dir| dir.is_dir())
+}
+
+pub fn default_path(day: u32) -> aoc_core::Result<PathBuf> {
+    let dirs = input_dirs();
+    dirs.iter()
+        .map(|dir| dir.join(file_name(day)))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            let tried = dirs.iter().map(|dir| dir.display().to_string()).collect::<Vec<_>>();
+            format!("no input for day {}, looked for {} in {}", day, file_name(day), tried.join(", ")).into()
+        })
+}
+
+pub fn read(source: &Source, day: u32) -> aoc_core::Result<String> {
+    match source {
+        Source::Stdin => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            Ok(buf)
+        }
+        Source::Path(path) => {
+            fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err).into())
+        }
+        // aoc new leaves an empty file as the slot for the puzzle input
+        Source::Default => {
+            let path = default_path(day)?;
+            let buf = read(&Source::Path(path.clone()), day)?;
+            if buf.is_empty() {
+                return Err(format!("the input for day {} is empty, paste it into {}", day, path.display()).into());
+            }
+            Ok(buf)
+        }
+    }
+}
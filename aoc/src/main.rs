@@ -0,0 +1,128 @@
+use std::env;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc_core::log::{self, Level};
+use args::Args;
+use input::Source;
+use output::{Format, Record};
+
+mod all;
+mod answers;
+mod args;
+mod bench;
+mod diff;
+mod fetch;
+mod fuzz;
+mod generate;
+mod input;
+mod output;
+mod registry;
+mod repl;
+mod scaffold;
+mod timing;
+mod verify;
+
+const USAGE: &str = "\
+usage: aoc [-v|-vv] run <day> [<part>] [--input <path>|-] [--format text|json|csv] [--set <name>=<value>...] [--explain]
+       aoc [-v|-vv] run --all [--jobs <n>] [--timeout <ms>] [--format text|json|csv]
+       aoc [-v|-vv] verify [<day>] [--answers <path>] [--record]
+       aoc [-v|-vv] bench [<day>] [--runs <n>] [--save <path>] [--baseline <path>] [--threshold <percent>]
+       aoc gen <day> [--seed <n>] [--size <n>] [--output <path>]
+       aoc diff [<day>] [--seeds <n>] [--size <n>]
+       aoc fuzz [<day>] [--cases <n>] [--seed <n>] [--timeout <ms>]
+       aoc new <day> [--root <path>]
+       aoc [-v|-vv] fetch <day>
+       aoc [-v|-vv] repl <day> [--input <path>] [--set <name>=<value>...]";
+
+// Solves one or both parts of a day, or every day with --all, and prints
+// the answers in the requested format.
+fn run(raw: &[String]) -> aoc_core::Result<()> {
+    let args = Args::parse(raw, &["input", "format", "jobs", "timeout", "set"], &["all", "explain"])?;
+    let format = Format::from_arg(args.value("format"))?;
+    if args.switch("all") {
+        if args.value("input").is_some() || args.value("set").is_some() || args.switch("explain") {
+            return Err("--all runs every day as is, it doesn't take --input, --set or --explain".into());
+        }
+        return all::run_all(&args, format);
+    }
+    if args.value("jobs").is_some() || args.value("timeout").is_some() {
+        return Err("--jobs and --timeout only go with --all".into());
+    }
+    let day = args.number(0, "day")?;
+    let part = args.maybe_number(1, "part")?;
+
+    let entry = registry::find(day).ok_or_else(|| format!("no solver registered for day {}", day))?;
+    let parts = match part {
+        None => vec![1, 2],
+        Some(part @ (1 | 2)) => vec![part],
+        Some(part) => return Err(format!("day {} has no part {}", day, part).into()),
+    };
+
+    let buf = input::read(&Source::from_arg(args.value("input")), day)?;
+    let solver = entry.build(args.values("set"))?;
+    // the explanation comes first, it matters most when solving fails
+    let mut parsed = None;
+    if args.switch("explain") {
+        if format != Format::Text {
+            return Err("--explain only goes with text output".into());
+        }
+        let input = parsed.insert(solver.parse_input(&buf)?);
+        for &part in &parts {
+            let text = solver
+                .explain_part(part, input)
+                .ok_or_else(|| format!("day {} can't explain its answers", day))?;
+            println!("day {} part {}:\n{}", day, part, text);
+        }
+    }
+    let mut records = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        // the time includes parsing, unless the input is parsed already
+        let value = match &parsed {
+            Some(input) => solver.solve_part(part, input)?,
+            None => solver.solve(part, &buf)?,
+        };
+        records.push(Record { day, part, value, elapsed: start.elapsed() });
+    }
+    print!("{}", output::render(format, &records));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    // -v and -vv work with every command, so they are taken out before the
+    // command sees its arguments
+    let (verbose, args): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|arg| arg == "-v" || arg == "-vv");
+    let level = match verbose.iter().map(|arg| arg.len() - 1).sum::<usize>() {
+        0 => Level::Quiet,
+        1 => Level::Debug,
+        _ => Level::Trace,
+    };
+    log::set_level(level);
+
+    let res = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => Args::parse(&args[1..], &["runs", "save", "baseline", "threshold"], &[])
+            .and_then(|args| bench::bench(&args)),
+        Some("gen") => Args::parse(&args[1..], &["seed", "size", "output"], &[])
+            .and_then(|args| generate::generate(&args)),
+        Some("diff") => Args::parse(&args[1..], &["seeds", "size"], &[]).and_then(|args| diff::diff(&args)),
+        Some("fuzz") => Args::parse(&args[1..], &["cases", "seed", "timeout"], &[]).and_then(|args| fuzz::fuzz(&args)),
+        Some("fetch") => Args::parse(&args[1..], &[], &[]).and_then(|args| fetch::fetch(&args)),
+        Some("new") => Args::parse(&args[1..], &["root"], &[]).and_then(|args| scaffold::new_day(&args)),
+        Some("repl") => Args::parse(&args[1..], &["input", "set"], &[]).and_then(|args| repl::repl(&args)),
+        Some("verify") => Args::parse(&args[1..], &["answers"], &["record"]).and_then(|args| verify::verify(&args)),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_core::Answer;
+
+use crate::timing::format_duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(arg: Option<&str>) -> aoc_core::Result<Self> {
+        match arg {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            Some(other) => Err(format!("unknown output format {:?}, expected text, json or csv", other).into()),
+        }
+    }
+}
+
+// One solved part, elapsed includes parsing the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub value: Answer,
+    pub elapsed: Duration,
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// Text is meant for people, JSON (an array of objects) and CSV (with a
+// header line) for scripts. Durations are nanoseconds in the latter two.
+pub fn render(format: Format, records: &[Record]) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Text => {
+            for r in records {
+                let _ = writeln!(out, "day {} part {}: {} ({})", r.day, r.part, r.value, format_duration(r.elapsed));
+            }
+        }
+        Format::Json => {
+            out.push('[');
+            for (i, r) in records.iter().enumerate() {
+                let value = match &r.value {
+                    Answer::Text(s) => json_string(s),
+                    number => number.to_string(),
+                };
+                let sep = if i == 0 { "" } else { "," };
+                let _ = write!(
+                    out,
+                    "{}\n  {{\"day\": {}, \"part\": {}, \"value\": {}, \"elapsed_ns\": {}}}",
+                    sep,
+                    r.day,
+                    r.part,
+                    value,
+                    r.elapsed.as_nanos()
+                );
+            }
+            out.push_str(if records.is_empty() { "]\n" } else { "\n]\n" });
+        }
+        Format::Csv => {
+            out.push_str("day,part,value,elapsed_ns\n");
+            for r in records {
+                let value = csv_field(&r.value.to_string());
+                let _ = writeln!(out, "{},{},{},{}", r.day, r.part, value, r.elapsed.as_nanos());
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record { day: 1, part: 1, value: Answer::Unsigned(142), elapsed: Duration::from_micros(12) },
+            Record { day: 9, part: 2, value: Answer::Signed(-2), elapsed: Duration::from_nanos(1500) },
+            Record { day: 25, part: 1, value: Answer::Text("a \"b\",c".to_string()), elapsed: Duration::ZERO },
+        ]
+    }
+
+    #[test]
+    fn render_text() {
+        assert_eq!(
+            render(Format::Text, &records()[..2]),
+            "day 1 part 1: 142 (12.0 µs)\nday 9 part 2: -2 (1.5 µs)\n"
+        );
+    }
+
+    #[test]
+    fn render_json() {
+        assert_eq!(render(Format::Json, &[]), "[]\n");
+        assert_eq!(
+            render(Format::Json, &records()),
+            "[\n  {\"day\": 1, \"part\": 1, \"value\": 142, \"elapsed_ns\": 12000},\
+             \n  {\"day\": 9, \"part\": 2, \"value\": -2, \"elapsed_ns\": 1500},\
+             \n  {\"day\": 25, \"part\": 1, \"value\": \"a \\\"b\\\",c\", \"elapsed_ns\": 0}\n]\n"
+        );
+    }
+
+    #[test]
+    fn render_csv() {
+        assert_eq!(
+            render(Format::Csv, &records()),
+            "day,part,value,elapsed_ns\n1,1,142,12000\n9,2,-2,1500\n25,1,\"a \"\"b\"\",c\",0\n"
+        );
+    }
+}
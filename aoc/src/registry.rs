@@ -0,0 +1,55 @@
+use aoc_core::DynSolver;
+
+pub struct Day {
+    pub day: u32,
+    pub solver: fn() -> Box<dyn DynSolver>,
+}
+
+macro_rules! day {
+    ($day:literal, $solver:path) => {
+        Day { day: $day, solver: || Box::<$solver>::default() }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+];
+
+impl Day {
+    // The solver of the day with the name=value settings of --set applied.
+    pub fn build(&self, settings: &[String]) -> aoc_core::Result<Box<dyn DynSolver>> {
+        let mut solver = (self.solver)();
+        for setting in settings {
+            let (name, value) =
+                setting.split_once('=').ok_or_else(|| format!("expected name=value, got {:?}", setting))?;
+            solver.set_option(name, value)?;
+        }
+        Ok(solver)
+    }
+}
+
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+// Every registered day, or just the given one.
+pub fn select(only: Option<u32>) -> aoc_core::Result<Vec<&'static Day>> {
+    let days: Vec<&Day> = DAYS.iter().filter(|entry| only.is_none_or(|day| entry.day == day)).collect();
+    if days.is_empty() {
+        return Err(format!("no solver registered for day {}", only.unwrap_or_default()).into());
+    }
+    Ok(days)
+}
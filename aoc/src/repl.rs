@@ -0,0 +1,159 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::time::Instant;
+
+use aoc_core::session::Session;
+use aoc_core::solver::Parsed;
+use aoc_core::DynSolver;
+
+use crate::args::Args;
+use crate::input::{self, Source};
+use crate::registry;
+use crate::timing::format_duration;
+
+const BUILTINS: &[(&str, &str)] = &[
+    ("part1, part2", "solve a part on the loaded input"),
+    ("explain <part>", "how the answer of the part comes about, for days that can tell"),
+    ("show input", "the parsed input printed back, for days that can"),
+    ("reload", "read and parse the input again, which also resets the day's state"),
+    ("help", "this list"),
+    ("quit", "leave, as does end of input"),
+];
+
+// A day with its input parsed once, plus whatever state the day lets us
+// explore.
+pub struct Repl {
+    day: u32,
+    source: Source,
+    settings: Vec<String>,
+    solver: Box<dyn DynSolver>,
+    parsed: Parsed,
+    session: Option<Box<dyn Session>>,
+}
+
+impl Repl {
+    pub fn load(day: u32, source: Source, settings: &[String]) -> aoc_core::Result<Self> {
+        let entry = registry::find(day).ok_or_else(|| format!("no solver registered for day {}", day))?;
+        let solver = entry.build(settings)?;
+        let parsed = solver.parse_input(&input::read(&source, day)?)?;
+        let session = solver.open_session(&parsed);
+        Ok(Repl { day, source, settings: settings.to_vec(), solver, parsed, session })
+    }
+
+    fn help(&self) -> String {
+        let mut commands: Vec<(&str, &str)> = BUILTINS.to_vec();
+        if let Some(session) = &self.session {
+            commands.extend(session.help());
+        }
+        let width = commands.iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+        let lines: Vec<String> = commands.iter().map(|(usage, what)| format!("{:<width$}  {}", usage, what)).collect();
+        lines.join("\n")
+    }
+
+    fn solve(&self, part: u32) -> aoc_core::Result<String> {
+        let start = Instant::now();
+        let value = self.solver.solve_part(part, &self.parsed)?;
+        Ok(format!("{} ({})", value, format_duration(start.elapsed())))
+    }
+
+    // Runs one command line and returns what to show for it.
+    pub fn execute(&mut self, line: &str) -> aoc_core::Result<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        match (command, args) {
+            ("help", []) => return Ok(self.help()),
+            ("part1", []) | ("part", ["1"]) => return self.solve(1),
+            ("part2", []) | ("part", ["2"]) => return self.solve(2),
+            ("explain", [part]) => {
+                let part = part.parse().map_err(|_| format!("not a part: {:?}", part))?;
+                let text = self.solver.explain_part(part, &self.parsed);
+                return text.ok_or_else(|| format!("day {} can't explain part {}", self.day, part).into());
+            }
+            ("show", ["input"]) => {
+                let printed = self.solver.print_input(&self.parsed);
+                return printed.ok_or_else(|| format!("day {} can't print its input", self.day).into());
+            }
+            ("reload", []) => {
+                *self = Repl::load(self.day, self.source.clone(), &self.settings)?;
+                return Ok(format!("reloaded the input of day {}", self.day));
+            }
+            _ => {}
+        }
+        let session = self.session.as_mut();
+        match session.and_then(|session| session.run(command, args)) {
+            Some(out) => out,
+            None => Err(format!("unknown command {:?}, try help", line.trim()).into()),
+        }
+    }
+}
+
+// Reads commands line by line until quit or the end of input. Errors are
+// shown and the session goes on.
+pub fn run_lines(repl: &mut Repl, lines: impl BufRead, mut out: impl Write, prompt: bool) -> aoc_core::Result<()> {
+    let day = repl.day;
+    let show_prompt = |out: &mut dyn Write| -> io::Result<()> {
+        if prompt {
+            write!(out, "day{}> ", day)?;
+            out.flush()?;
+        }
+        Ok(())
+    };
+    show_prompt(&mut out)?;
+    for line in lines.lines() {
+        let line = line?;
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+        match repl.execute(&line) {
+            Ok(text) if text.is_empty() => {}
+            Ok(text) => writeln!(out, "{}", text)?,
+            Err(err) => writeln!(out, "error: {}", err)?,
+        }
+        show_prompt(&mut out)?;
+    }
+    Ok(())
+}
+
+// Loads the input of a day once and takes commands from stdin.
+pub fn repl(args: &Args) -> aoc_core::Result<()> {
+    let day = args.number(0, "day")?;
+    let source = Source::from_arg(args.value("input"));
+    if matches!(source, Source::Stdin) {
+        return Err("the repl reads its commands from stdin, the input has to come from a file".into());
+    }
+    let mut repl = Repl::load(day, source, args.values("set"))?;
+    let stdin = io::stdin();
+    let prompt = stdin.is_terminal();
+    if prompt {
+        println!("day {} loaded, try help", day);
+    }
+    run_lines(&mut repl, stdin.lock(), io::stdout(), prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn commands_run_against_the_loaded_input() {
+        let path = env::temp_dir().join(format!("aoc-repl-{}.txt", std::process::id()));
+        fs::write(&path, include_str!("../../day14/tests/example.txt")).unwrap();
+        let mut repl = Repl::load(14, Source::Path(path.clone()), &[]).unwrap();
+        let script = "part1\nstep 2\nbogus\n\nreload\nload\nquit\npart2\n";
+        let mut out = Vec::new();
+        run_lines(&mut repl, script.as_bytes(), &mut out, false).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("136 ("), "{}", out);
+        assert_eq!(lines[1], "after 2 cycles the load on the north beams is 69");
+        assert_eq!(lines[2], "error: unknown command \"bogus\", try help");
+        assert_eq!(lines[3], "reloaded the input of day 14");
+        assert_eq!(lines[4], "after 0 cycles the load on the north beams is 104");
+        assert_eq!(lines.len(), 5);
+    }
+}
@@ -0,0 +1,238 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::args::Args;
+use crate::input;
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{N}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+
+[lints]
+workspace = true
+"#;
+
+const LIB_RS: &str = r#"use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+#[derive(Default)]
+pub struct Day{N};
+
+impl Solver for Day{N} {
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        Ok(buf.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(&self, _data: &Self::Input) -> aoc_core::Result<u64> {
+        Ok(0)
+    }
+
+    fn part2(&self, _data: &Self::Input) -> aoc_core::Result<u64> {
+        Ok(0)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+"#;
+
+const GEN_RS: &str = r#"use aoc_core::rng::Rng;
+
+// size lines of random letters until there is a real generator
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.between(1, 20) {
+            buf.push((b'a' + rng.below(26) as u8) as char);
+        }
+        buf.push('\n');
+    }
+    buf
+}
+"#;
+
+const EXAMPLES_RS: &str = r#"use aoc_core::Solver;
+use day{N}::Day{N};
+
+#[test]
+#[ignore = "paste the example into tests/example.txt and fill in its answer"]
+fn part1_example() {
+    let input = Day{N}.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day{N}.part1(&input).unwrap(), 0);
+}
+
+#[test]
+#[ignore = "paste the example into tests/example.txt and fill in its answer"]
+fn part2_example() {
+    let input = Day{N}.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day{N}.part2(&input).unwrap(), 0);
+}
+"#;
+
+// The workspace to add the day to: the first directory up from the current
+// one with a workspace Cargo.toml, otherwise the one this binary was built
+// from.
+fn workspace_root() -> aoc_core::Result<PathBuf> {
+    let is_root = |dir: &Path| {
+        fs::read_to_string(dir.join("Cargo.toml"))
+            .is_ok_and(|toml| toml.lines().any(|line| line.trim() == "[workspace]"))
+    };
+    if let Ok(cwd) = env::current_dir() {
+        if let Some(dir) = cwd.ancestors().find(|dir| is_root(dir)) {
+            return Ok(dir.to_path_buf());
+        }
+    }
+    let built = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    if is_root(&built) {
+        return Ok(built);
+    }
+    Err("no workspace found, pass --root".into())
+}
+
+// Inserts line among the lines that belong to a day, keeping them ordered by
+// day. day_of picks those lines out and tells which day they are for.
+pub fn insert_line(text: &str, day: u32, line: &str, day_of: impl Fn(&str) -> Option<u32>) -> aoc_core::Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u32)> = lines.iter().enumerate().filter_map(|(i, l)| Some((i, day_of(l)?))).collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("day {} is already there", day).into());
+    }
+    let at = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => days.first().ok_or("no days to add to")?.0,
+    };
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend(&lines[at..]);
+    Ok(out.join("\n") + "\n")
+}
+
+fn number(s: &str) -> Option<u32> {
+    s.parse().ok()
+}
+
+//     "day7",
+fn member_day(line: &str) -> Option<u32> {
+    number(line.trim().strip_prefix("\"day")?.strip_suffix("\",")?)
+}
+
+// day7 = { path = "../day7" }
+fn dependency_day(line: &str) -> Option<u32> {
+    number(line.strip_prefix("day")?.split_once(' ')?.0)
+}
+
+//     day!(7, day7::Day7),
+fn registry_day(line: &str) -> Option<u32> {
+    number(line.trim().strip_prefix("day!(")?.split_once(',')?.0)
+}
+
+fn edit(path: &Path, day: u32, line: &str, day_of: fn(&str) -> Option<u32>) -> aoc_core::Result<(PathBuf, String)> {
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let text = insert_line(&text, day, line, day_of).map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok((path.to_path_buf(), text))
+}
+
+// Adds a crate for a new day to the workspace: a solver that parses lines
+// and answers 0, a placeholder generator, ignored example tests waiting for
+// the example and an empty input file. The crate is registered with the
+// workspace and the runner, so that aoc run <day> works right away.
+pub fn new_day(args: &Args) -> aoc_core::Result<()> {
+    let day = args.number(0, "day")?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}", day).into());
+    }
+    let root = match args.value("root") {
+        Some(root) => PathBuf::from(root),
+        None => workspace_root()?,
+    };
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    // all edits are worked out before anything is written, so that a
+    // workspace that doesn't look as expected is left alone
+    let edits = [
+        edit(&root.join("Cargo.toml"), day, &format!("    \"{}\",", name), member_day)?,
+        edit(&root.join("aoc/Cargo.toml"), day, &format!("{0} = {{ path = \"../{0}\" }}", name), dependency_day)?,
+        edit(&root.join("aoc/src/registry.rs"), day, &format!("    day!({0}, day{0}::Day{0}),", day), registry_day)?,
+    ];
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("src/lib.rs", LIB_RS),
+        ("src/gen.rs", GEN_RS),
+        ("tests/examples.rs", EXAMPLES_RS),
+        ("tests/example.txt", ""),
+    ];
+    for (file, template) in files {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().unwrap_or(&dir))?;
+        fs::write(&path, template.replace("{N}", &day.to_string()))?;
+        println!("created {}", path.display());
+    }
+    for (path, text) in edits {
+        fs::write(&path, text)?;
+        println!("updated {}", path.display());
+    }
+
+    let inputs = root.join("inputs");
+    let slot = inputs.join(input::file_name(day));
+    if !slot.exists() {
+        fs::create_dir_all(&inputs)?;
+        fs::write(&slot, "")?;
+        println!("created {}, paste the puzzle input there", slot.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_line_keeps_days_in_order() {
+        let members = "members = [\n    \"aoc\",\n    \"day1\",\n    \"day9\",\n    \"day10\",\n]\n";
+        let text = insert_line(members, 2, "    \"day2\",", member_day).unwrap();
+        assert_eq!(text, "members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day9\",\n    \"day10\",\n]\n");
+        let text = insert_line(members, 11, "    \"day11\",", member_day).unwrap();
+        assert!(text.ends_with("    \"day10\",\n    \"day11\",\n]\n"));
+        assert!(insert_line(members, 9, "    \"day9\",", member_day).is_err());
+
+        let registry = "pub const DAYS: &[Day] = &[\n    day!(3, day3::Day3),\n];\n";
+        let text = insert_line(registry, 1, "    day!(1, day1::Day1),", registry_day).unwrap();
+        assert_eq!(text, "pub const DAYS: &[Day] = &[\n    day!(1, day1::Day1),\n    day!(3, day3::Day3),\n];\n");
+        assert!(insert_line("[dependencies]\n", 1, "day1 = { path = \"../day1\" }", dependency_day).is_err());
+    }
+
+    #[test]
+    fn new_day_writes_the_crate_and_registers_it() {
+        let root = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n]\n").unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\nday1 = { path = \"../day1\" }\n").unwrap();
+        fs::write(root.join("aoc/src/registry.rs"), "&[\n    day!(1, day1::Day1),\n];\n").unwrap();
+
+        let raw = ["15".to_string(), format!("--root={}", root.display())];
+        new_day(&Args::parse(&raw, &["root"], &[]).unwrap()).unwrap();
+        let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains("    day!(15, day15::Day15),\n];"));
+        assert!(fs::read_to_string(root.join("day15/src/lib.rs")).unwrap().contains("impl Solver for Day15 {"));
+        assert_eq!(fs::read_to_string(root.join("inputs/day15.txt")).unwrap(), "");
+        // a second run must not touch anything
+        assert!(new_day(&Args::parse(&raw, &["root"], &[]).unwrap()).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+// short human readable duration with a unit that fits its size
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2} s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2} ms", secs * 1e3)
+    } else {
+        format!("{:.1} µs", secs * 1e6)
+    }
+}
+
+// summary of repeated measurements of the same thing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // None without samples, the median of an even count is the lower one
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        Some(Self {
+            min: *sorted.first()?,
+            median: sorted[(sorted.len() - 1) / 2],
+            max: *sorted.last()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let ms = |ms: &[u64]| ms.iter().map(|ms| Duration::from_millis(*ms)).collect::<Vec<_>>();
+        let stats = Stats::of(&ms(&[5, 1, 9, 3])).unwrap();
+        assert_eq!(stats, Stats { min: ms(&[1])[0], median: ms(&[3])[0], max: ms(&[9])[0] });
+        assert_eq!(Stats::of(&ms(&[2, 7, 4])).unwrap().median, Duration::from_millis(4));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn picks_the_unit() {
+        assert_eq!(format_duration(Duration::from_micros(12)), "12.0 µs");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50 s");
+    }
+}
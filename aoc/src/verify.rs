@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::answers::{self, Answers, Entry};
+use crate::args::Args;
+use crate::input::{self, Source};
+use crate::registry;
+use crate::timing::format_duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+// first line only, parse errors come with a snippet of the input
+fn short_error(err: &aoc_core::Error) -> String {
+    let msg = err.to_string();
+    format!("error: {}", msg.lines().next().unwrap_or(""))
+}
+
+// Runs every registered solver (or just the one for the given day) on its
+// default input and compares the results against the answers file.
+pub fn verify(args: &Args) -> aoc_core::Result<()> {
+    let only = args.maybe_number(0, "day")?;
+    let record = args.switch("record");
+    let path = match args.value("answers") {
+        Some(path) => PathBuf::from(path),
+        None => input::default_dir().ok_or("no inputs directory found")?.join(answers::FILE_NAME),
+    };
+    let mut answers = Answers::load(&path)?;
+
+    let days = registry::select(only)?;
+
+    let mut counts = [0usize; 3];
+    let mut recorded = 0;
+    println!("{:>3} {:>4}  {:<7} {:>10}  answer", "day", "part", "status", "time");
+    for entry in days {
+        let buf = input::read(&Source::Default, entry.day).ok();
+        let hash = buf.as_deref().map(answers::input_hash);
+        let solver = (entry.solver)();
+        for part in 1..=2 {
+            let Some(buf) = &buf else {
+                counts[Status::Missing as usize] += 1;
+                println!("{:>3} {:>4}  {:<7} {:>10}  no input", entry.day, part, Status::Missing.label(), "-");
+                continue;
+            };
+            let start = Instant::now();
+            let result = solver.solve(part, buf).map(|answer| answer.to_string());
+            let elapsed = start.elapsed();
+
+            let known = answers.get(entry.day, part);
+            let matching = known.filter(|known| Some(&known.input_hash) == hash.as_ref());
+            let (status, note) = match (&result, matching) {
+                (Err(err), _) => (Status::Fail, short_error(err)),
+                (Ok(value), Some(known)) if *value == known.value => (Status::Pass, value.clone()),
+                (Ok(value), Some(known)) => (Status::Fail, format!("{}, expected {}", value, known.value)),
+                (Ok(value), None) => {
+                    let why = if known.is_some() { "input changed" } else { "no known answer" };
+                    if record {
+                        answers.set(Entry {
+                            day: entry.day,
+                            part,
+                            input_hash: hash.clone().unwrap_or_default(),
+                            value: value.clone(),
+                        });
+                        recorded += 1;
+                        (Status::Missing, format!("{} ({}, recorded)", value, why))
+                    } else {
+                        (Status::Missing, format!("{} ({})", value, why))
+                    }
+                }
+            };
+            counts[status as usize] += 1;
+            println!(
+                "{:>3} {:>4}  {:<7} {:>10}  {}",
+                entry.day, part, status.label(), format_duration(elapsed), note
+            );
+        }
+    }
+
+    let [passed, failed, missing] = counts;
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if recorded > 0 {
+        answers.save(&path)?;
+        println!("recorded {} answers in {}", recorded, path.display());
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} answers did not match", failed, passed + failed + missing).into());
+    }
+    Ok(())
+}
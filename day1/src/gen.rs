@@ -0,0 +1,25 @@
+use aoc_core::rng::Rng;
+
+use crate::scan::WORDS;
+
+// size lines of letters, digits and spelled out digits, every line has at
+// least one real digit so that part 1 has an answer as well
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let mut pieces: Vec<String> = (0..rng.between(1, 8))
+            .map(|_| match rng.below(3) {
+                0 => rng.between(1, 9).to_string(),
+                1 => rng.pick(&WORDS).to_string(),
+                _ => (0..rng.between(1, 4)).map(|_| (b'a' + rng.below(26) as u8) as char).collect(),
+            })
+            .collect();
+        if !pieces.iter().any(|piece| piece.bytes().any(|b| b.is_ascii_digit())) {
+            let at = rng.below(pieces.len() + 1);
+            pieces.insert(at, rng.between(1, 9).to_string());
+        }
+        buf.push_str(&pieces.concat());
+        buf.push('\n');
+    }
+    buf
+}
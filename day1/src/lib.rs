@@ -0,0 +1,114 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use aoc_core::math::checked_sum;
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+mod scan;
+
+pub use scan::{calibration_value, first_and_last, Rule, Token, Vocabulary};
+
+fn sum_of_values(lines: &[String], rule: Rule) -> aoc_core::Result<u32> {
+    let values = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calibration_value(line, rule)
+                .ok_or_else(|| format!("line {} has no digits: {:?}", i + 1, line))
+        })
+        .collect::<Result<Vec<u32>, String>>()?;
+    checked_sum(values)
+}
+
+// 1-based column of a byte position, counting characters
+fn column(line: &str, at: usize) -> usize {
+    line[..at].chars().count() + 1
+}
+
+// One line per calibration line with the tokens that made its value, then
+// the sum and the lines without digits.
+fn explain(lines: &[String], rule: Rule) -> String {
+    let mut out = String::new();
+    let mut sum = 0u64;
+    let mut without = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some((first, last)) = first_and_last(line, rule) else {
+            let _ = writeln!(out, "line {}: no digits in {:?}", i + 1, line);
+            without.push((i + 1).to_string());
+            continue;
+        };
+        let value = first.value * 10 + last.value;
+        sum += value as u64;
+        let _ = writeln!(
+            out,
+            "line {}: {} from {:?} at column {} and {:?} at column {}",
+            i + 1,
+            value,
+            &line[first.start..first.end],
+            column(line, first.start),
+            &line[last.start..last.end],
+            column(line, last.start),
+        );
+    }
+    let _ = writeln!(out, "sum of {} values: {}", lines.len() - without.len(), sum);
+    if without.is_empty() {
+        let _ = writeln!(out, "every line has a digit");
+    } else {
+        let noun = if without.len() == 1 { "line" } else { "lines" };
+        let _ = writeln!(out, "{} {} without digits: {}", without.len(), noun, without.join(", "));
+    }
+    out
+}
+
+// The spelled out digits of part 2 are English unless configured otherwise.
+#[derive(Default)]
+pub struct Day1 {
+    vocabulary: Vocabulary,
+}
+
+impl Day1 {
+    pub fn with_vocabulary(vocabulary: Vocabulary) -> Self {
+        Day1 { vocabulary }
+    }
+}
+
+impl Solver for Day1 {
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        Ok(buf.trim_end().lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> aoc_core::Result<u32> {
+        sum_of_values(lines, Rule::Digits)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> aoc_core::Result<u32> {
+        sum_of_values(lines, Rule::Words(&self.vocabulary))
+    }
+
+    fn explain(&self, part: u32, lines: &Self::Input) -> Option<String> {
+        match part {
+            1 => Some(explain(lines, Rule::Digits)),
+            2 => Some(explain(lines, Rule::Words(&self.vocabulary))),
+            _ => None,
+        }
+    }
+
+    // words=english|german|french or words-file=<path> with a word list
+    fn configure(&mut self, name: &str, value: &str) -> aoc_core::Result<()> {
+        self.vocabulary = match name {
+            "words" => Vocabulary::builtin(value)?,
+            "words-file" => Vocabulary::load(Path::new(value))?,
+            _ => return Err(format!("day 1 has no option {:?}, there are words and words-file", name).into()),
+        };
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
@@ -0,0 +1,208 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_core::aho_corasick::AhoCorasick;
+
+// Finds the digits of a calibration line in one pass, without rewriting the
+// line. Spelled out digits may overlap ("twone" is 2 then 1), the matcher
+// reports every match, overlapping or not.
+
+pub const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const GERMAN: [&str; 9] = ["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+const FRENCH: [&str; 9] = ["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"];
+
+// the vocabularies that can be picked by name
+pub const BUILTIN: [&str; 3] = ["english", "german", "french"];
+
+// The spelled out digits the scanner knows, each word with its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    // patterns 0 to 9 are the digits, the words follow in order
+    matcher: AhoCorasick,
+}
+
+fn matcher(words: &[(String, u32)]) -> aoc_core::Result<AhoCorasick> {
+    let digits = (0..10).map(|d| d.to_string());
+    let patterns: Vec<String> = digits.chain(words.iter().map(|(word, _)| word.clone())).collect();
+    AhoCorasick::new(&patterns)
+}
+
+impl Vocabulary {
+    pub fn new(words: Vec<(String, u32)>) -> aoc_core::Result<Self> {
+        for (word, value) in &words {
+            if word.is_empty() || word.contains(|c: char| c.is_whitespace() || c.is_ascii_digit()) {
+                return Err(format!("{:?} can't be a number word", word).into());
+            }
+            if *value > 9 {
+                return Err(format!("{} for {:?} isn't a digit", value, word).into());
+            }
+        }
+        let matcher = matcher(&words)?;
+        Ok(Vocabulary { words, matcher })
+    }
+
+    // one to nine, in order
+    fn one_to_nine(words: [&str; 9]) -> Self {
+        let words = words.iter().zip(1..).map(|(word, value)| (word.to_string(), value)).collect();
+        Self::new(words).expect("the built in vocabularies are valid")
+    }
+
+    pub fn english() -> Self {
+        Self::one_to_nine(WORDS)
+    }
+
+    pub fn german() -> Self {
+        Self::one_to_nine(GERMAN)
+    }
+
+    pub fn french() -> Self {
+        Self::one_to_nine(FRENCH)
+    }
+
+    pub fn builtin(name: &str) -> aoc_core::Result<Self> {
+        match name {
+            "english" => Ok(Self::english()),
+            "german" => Ok(Self::german()),
+            "french" => Ok(Self::french()),
+            _ => Err(format!("unknown vocabulary {:?}, expected one of {}", name, BUILTIN.join(", ")).into()),
+        }
+    }
+
+    // A word list, one "<word> <digit>" per line. Blank lines and lines
+    // starting with # are skipped.
+    pub fn parse(text: &str) -> aoc_core::Result<Self> {
+        let mut words = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, value) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("line {}: expected a word and its digit", i + 1))?;
+            let value = value.trim().parse().map_err(|_| format!("line {}: not a digit: {:?}", i + 1, value.trim()))?;
+            words.push((word.to_string(), value));
+        }
+        Self::new(words).map_err(|err| format!("word list: {}", err).into())
+    }
+
+    pub fn load(path: &Path) -> aoc_core::Result<Self> {
+        let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+// what counts as a digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule<'a> {
+    // 0-9 only, part 1
+    Digits,
+    // 0-9 and the words of the vocabulary, part 2
+    Words(&'a Vocabulary),
+}
+
+// A digit or number word in a line, start..end are byte positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+// Every digit in the line under the rule, overlapping words included.
+// Matches are on bytes, but UTF-8 never has a leading byte inside a
+// character, so a word can only match where a character starts.
+pub fn tokens<'a>(line: &'a str, rule: Rule<'a>) -> Box<dyn Iterator<Item = Token> + 'a> {
+    match rule {
+        Rule::Digits => Box::new(
+            line.bytes()
+                .enumerate()
+                .filter(|(_, b)| b.is_ascii_digit())
+                .map(|(at, b)| Token { start: at, end: at + 1, value: (b - b'0') as u32 }),
+        ),
+        Rule::Words(vocabulary) => Box::new(vocabulary.matcher.find_overlapping(line.as_bytes()).map(|m| {
+            let value = match m.pattern {
+                digit @ 0..=9 => digit as u32,
+                word => vocabulary.words[word - 10].1,
+            };
+            Token { start: m.start, end: m.end, value }
+        })),
+    }
+}
+
+// The first and the last digit of the line, the same token if there is only
+// one. Where words start at the same position the longest one counts.
+pub fn first_and_last(line: &str, rule: Rule) -> Option<(Token, Token)> {
+    let mut ends: Option<(Token, Token)> = None;
+    for token in tokens(line, rule) {
+        let Some((first, last)) = &mut ends else {
+            ends = Some((token, token));
+            continue;
+        };
+        if token.start < first.start || (token.start == first.start && token.end > first.end) {
+            *first = token;
+        }
+        if (token.start, token.end) > (last.start, last.end) {
+            *last = token;
+        }
+    }
+    ends
+}
+
+// None if the line has no digits at all
+pub fn calibration_value(line: &str, rule: Rule) -> Option<u32> {
+    let (first, last) = first_and_last(line, rule)?;
+    Some(first.value * 10 + last.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_words_count_from_both_ends() {
+        let english = Vocabulary::english();
+        assert_eq!(calibration_value("twone", Rule::Words(&english)), Some(21));
+        assert_eq!(calibration_value("eightwothree", Rule::Words(&english)), Some(83));
+        assert_eq!(calibration_value("zoneight234", Rule::Words(&english)), Some(14));
+        assert_eq!(calibration_value("oneight", Rule::Words(&english)), Some(18));
+        assert_eq!(calibration_value("xtwone3four", Rule::Words(&english)), Some(24));
+    }
+
+    #[test]
+    fn digits_rule_ignores_words() {
+        let english = Vocabulary::english();
+        assert_eq!(calibration_value("two1nine", Rule::Digits), Some(11));
+        assert_eq!(calibration_value("treb7uchet", Rule::Digits), Some(77));
+        assert_eq!(calibration_value("twone", Rule::Digits), None);
+        assert_eq!(calibration_value("ünë2", Rule::Words(&english)), Some(22));
+        assert_eq!(calibration_value("", Rule::Words(&english)), None);
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let german = Vocabulary::german();
+        assert_eq!(calibration_value("xzweinsiebenx", Rule::Words(&german)), Some(27));
+        assert_eq!(calibration_value("fünfzig", Rule::Words(&german)), Some(55));
+        let french = Vocabulary::french();
+        assert_eq!(calibration_value("septrois", Rule::Words(&french)), Some(73));
+        assert_eq!(calibration_value("twone", Rule::Words(&french)), None);
+
+        let custom = Vocabulary::parse("# spanish, partly\nuno 1\n\ndos 2\ndoscientos 9\n").unwrap();
+        assert_eq!(calibration_value("unodoscientos", Rule::Words(&custom)), Some(19));
+        assert!(Vocabulary::parse("uno\n").is_err());
+        assert!(Vocabulary::parse("uno 10\n").is_err());
+        assert!(Vocabulary::builtin("klingon").is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_core::Solver;
+use day1::{Day1, Vocabulary};
+
+#[test]
+fn part1_example() {
+    let day = Day1::default();
+    let input = day.parse(include_str!("example1.txt")).unwrap();
+    assert_eq!(day.part1(&input).unwrap(), 142);
+}
+
+#[test]
+fn part2_example() {
+    let day = Day1::default();
+    let input = day.parse(include_str!("example2.txt")).unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 281);
+}
+
+#[test]
+fn line_without_digits_is_an_error() {
+    let day = Day1::default();
+    let input = day.parse(include_str!("example2.txt")).unwrap();
+    assert!(day.part1(&input).is_err());
+}
+
+#[test]
+fn part2_with_another_vocabulary() {
+    let day = Day1::with_vocabulary(Vocabulary::german());
+    let input = day.parse("zwei1neun\nachtzwei3\n7fünf\n").unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 29 + 83 + 75);
+}
+
+#[test]
+fn explain_names_the_tokens_and_the_lines_without_digits() {
+    let day = Day1::default();
+    let input = day.parse("xtwone3four\nabc\nzoneight234\n").unwrap();
+    let text = day.explain(2, &input).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "line 1: 24 from \"two\" at column 2 and \"four\" at column 8");
+    assert_eq!(lines[1], "line 2: no digits in \"abc\"");
+    assert_eq!(lines[2], "line 3: 14 from \"one\" at column 2 and \"4\" at column 11");
+    assert_eq!(lines[3], "sum of 2 values: 38");
+    assert_eq!(lines[4], "1 line without digits: 2");
+}
@@ -0,0 +1,138 @@
+use aoc_core::grid::Grid;
+use aoc_core::rng::Rng;
+use aoc_core::union_find::UnionFind;
+
+use crate::{TileType, EAST, SOUTH};
+
+// the eight squares around a square in clockwise order, starting north
+const RING: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+const JUNK: [char; 10] = ['|', '-', 'L', 'J', '7', 'F', '.', '.', '.', '.'];
+
+fn occupied(region: &Grid<bool>, i: isize, j: isize) -> bool {
+    i >= 0 && j >= 0 && region.get((i as usize, j as usize)).copied().unwrap_or(false)
+}
+
+// Adding the square keeps the region free of holes and of squares that only
+// touch at a corner, as long as the occupied squares around it form a single
+// run and no corner square hangs on without one of its two edge neighbors.
+fn can_grow(region: &Grid<bool>, (i, j): (usize, usize)) -> bool {
+    let ring: Vec<bool> = RING.iter().map(|(di, dj)| occupied(region, i as isize + di, j as isize + dj)).collect();
+    let pinched = (1..8).step_by(2).any(|k| ring[k] && !ring[k - 1] && !ring[(k + 1) % 8]);
+    let runs = (0..8).filter(|&k| !ring[k] && ring[(k + 1) % 8]).count();
+    !pinched && runs == 1
+}
+
+// The loop runs through the tile centers around a region of the squares
+// between them. The region grows one square at a time without ever getting
+// a hole or a pinch, so its border is a single closed loop. Tiles off the
+// loop get random pipes, any cycle among those is broken up and nothing but
+// the loop connects to S: there is exactly one loop.
+//
+// size is the width and height of the map.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(3);
+    let mut region = Grid::new(n - 1, n - 1, false);
+    let first = (rng.below(n - 1), rng.below(n - 1));
+    region[first] = true;
+    let mut candidates: Vec<(usize, usize)> = region.neighbors4(first).collect();
+    let mut area = 1;
+    while area < (n - 1) * (n - 1) / 3 && !candidates.is_empty() {
+        let square = candidates.swap_remove(rng.below(candidates.len()));
+        if region[square] || !can_grow(&region, square) {
+            continue;
+        }
+        region[square] = true;
+        area += 1;
+        candidates.extend(region.neighbors4(square).filter(|&pos| !region[pos]));
+    }
+
+    let mut tiles = Grid::new(n, n, '.');
+    let mut on_loop = Vec::new();
+    for (i, j) in tiles.positions() {
+        let (r, c) = (i as isize, j as isize);
+        let north = occupied(&region, r - 1, c - 1) != occupied(&region, r - 1, c);
+        let south = occupied(&region, r, c - 1) != occupied(&region, r, c);
+        let west = occupied(&region, r - 1, c - 1) != occupied(&region, r, c - 1);
+        let east = occupied(&region, r - 1, c) != occupied(&region, r, c);
+        let tile = match (north, south, west, east) {
+            (true, true, false, false) => '|',
+            (false, false, true, true) => '-',
+            (true, false, false, true) => 'L',
+            (true, false, true, false) => 'J',
+            (false, true, true, false) => '7',
+            (false, true, false, true) => 'F',
+            _ => continue,
+        };
+        tiles[(i, j)] = tile;
+        on_loop.push((i, j));
+    }
+    let mut loop_mask = Grid::new(n, n, false);
+    for &pos in &on_loop {
+        loop_mask[pos] = true;
+    }
+
+    for pos in tiles.positions() {
+        if !loop_mask[pos] {
+            tiles[pos] = *rng.pick(&JUNK);
+        }
+    }
+    break_junk_cycles(&mut tiles, &loop_mask);
+
+    let start = *rng.pick(&on_loop);
+    tiles[start] = 'S';
+    for next in tiles.neighbors4(start).collect::<Vec<_>>() {
+        if !loop_mask[next] {
+            tiles[next] = '.';
+        }
+    }
+
+    let mut buf = tiles.to_string();
+    buf.push('\n');
+    buf
+}
+
+fn connects(tiles: &Grid<char>, pos: (usize, usize), dir: (isize, isize)) -> bool {
+    TileType::from_char(tiles[pos]).is_some_and(|tile| tile.connections().contains(&dir))
+}
+
+// union-find over the junk pipes, an edge between two tiles that are already
+// connected closes a cycle and the second tile is cleared
+fn break_junk_cycles(tiles: &mut Grid<char>, loop_mask: &Grid<bool>) {
+    let width = tiles.width();
+    let mut sets = UnionFind::new(width * tiles.height());
+
+    for p in tiles.positions() {
+        for dir in [EAST, SOUTH] {
+            let Some(q) = tiles.step(p, dir) else {
+                continue;
+            };
+            if loop_mask[p] || loop_mask[q] || !connects(tiles, p, dir) || !connects(tiles, q, (-dir.0, -dir.1)) {
+                continue;
+            }
+            if !sets.union(p.0 * width + p.1, q.0 * width + q.1) {
+                tiles[q] = '.';
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    #[test]
+    fn there_is_exactly_one_loop() {
+        for seed in 0..20 {
+            let map = Map::parse(&generate(&mut Rng::new(seed), 15)).unwrap();
+            let (on_loop, farthest) = map.bfs();
+            let tiles: Vec<(usize, usize)> = on_loop.positions().filter(|&pos| on_loop[pos]).collect();
+            // connected, every tile has two neighbors on the loop: a single cycle
+            for &pos in &tiles {
+                assert_eq!(map.expand(pos).len(), 2, "seed {} tile {:?}", seed, pos);
+            }
+            assert_eq!(farthest, tiles.len() / 2);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use aoc_core::grid::{Grid, DIRECTIONS4};
+use aoc_core::parse::parse_all;
+use aoc_core::rng::Rng;
+use aoc_core::{trace, Solver};
+
+mod gen;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum TileType {
+    VerticalSegment = b'|',
+    HorizontalSegment = b'-',
+    NorthEastBendSegment = b'L',
+    NorthWestBendSegment = b'J',
+    SouthWestBendSegment = b'7',
+    SouthEastBendSegment = b'F',
+    StartSegment = b'S',
+    Ground = b'.',
+}
+
+impl From<TileType> for char {
+    fn from(val: TileType) -> Self {
+        val as u8 as char
+    }
+}
+
+impl TileType {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(TileType::VerticalSegment),
+            '-' => Some(TileType::HorizontalSegment),
+            'L' => Some(TileType::NorthEastBendSegment),
+            'J' => Some(TileType::NorthWestBendSegment),
+            '7' => Some(TileType::SouthWestBendSegment),
+            'F' => Some(TileType::SouthEastBendSegment),
+            'S' => Some(TileType::StartSegment),
+            '.' => Some(TileType::Ground),
+            _ => None,
+        }
+    }
+}
+
+const NORTH: (isize, isize) = (-1, 0);
+const EAST: (isize, isize) = (0, 1);
+const SOUTH: (isize, isize) = (1, 0);
+const WEST: (isize, isize) = (0, -1);
+
+impl TileType {
+    // directions the pipe on this tile leads to, the start tile could be
+    // any of them
+    fn connections(self) -> &'static [(isize, isize)] {
+        match self {
+            TileType::VerticalSegment => &[NORTH, SOUTH],
+            TileType::HorizontalSegment => &[WEST, EAST],
+            TileType::NorthEastBendSegment => &[NORTH, EAST],
+            TileType::NorthWestBendSegment => &[NORTH, WEST],
+            TileType::SouthWestBendSegment => &[SOUTH, WEST],
+            TileType::SouthEastBendSegment => &[SOUTH, EAST],
+            TileType::StartSegment => &DIRECTIONS4,
+            TileType::Ground => &[],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<TileType>,
+    start: (usize, usize),
+}
+
+impl Map {
+    fn parse(buf: &str) -> aoc_core::Result<Self> {
+        parse_all(buf, |p| {
+            let tiles = p.grid_with("a tile", TileType::from_char)?;
+            let start = tiles
+                .iter()
+                .filter(|(_, tile)| **tile == TileType::StartSegment)
+                .map(|(pos, _)| pos)
+                .last()
+                .ok_or_else(|| p.error("a start tile 'S' somewhere in the map"))?;
+            Ok(Self { tiles, start })
+        })
+    }
+
+    // neighbors that are connected to pos by a pipe going both ways
+    fn expand(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        self.tiles[pos]
+            .connections()
+            .iter()
+            .filter_map(|&(di, dj)| {
+                let next = self.tiles.step(pos, (di, dj))?;
+                let back = (-di, -dj);
+                if self.tiles[next].connections().contains(&back) {
+                    Some(next)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn init_mask(&self) -> Grid<bool> {
+        let mut mask = Grid::new(self.tiles.width(), self.tiles.height(), false);
+        mask[self.start] = true;
+        mask
+    }
+
+    // returns the tiles on the loop and the distance to the farthest of them
+    fn bfs(&self) -> (Grid<bool>, usize) {
+        let mut visited = self.init_mask();
+        let mut frontier: Vec<(usize, usize)> = vec![self.start];
+        let mut iterations = 0;
+        loop {
+            let mut expanse: Vec<(usize, usize)> = Vec::new();
+            for pos in frontier {
+                let candidates = self.expand(pos);
+                for pos in candidates {
+                    if !visited[pos] {
+                        expanse.push(pos);
+                        visited[pos] = true;
+                    }
+                }
+            }
+            if expanse.is_empty() {
+                break;
+            }
+            iterations += 1;
+            frontier = expanse;
+        } 
+        trace!(
+            "farthest point of the loop is {} steps away:\n{}",
+            iterations,
+            visited.map(|&on| if on { '#' } else { '.' })
+        );
+        (visited, iterations)
+    }
+}
+
+fn scale_up(pipe_map: &Grid<bool>, tilemap: &Map) -> Grid<bool> {
+    let mut scaled_map = Grid::new(2 * pipe_map.width(), 2 * pipe_map.height(), false);
+    for ((i, j), on_loop) in pipe_map.iter() {
+        if !on_loop {
+            continue;
+        }
+        scaled_map[(2 * i, 2 * j)] = true;
+        // the cell between (2i, 2j) and (2ni, 2nj) in the scaled map
+        for (ni, nj) in tilemap.expand((i, j)) {
+            scaled_map[(i + ni, j + nj)] = true;
+        }
+    }
+    scaled_map
+}
+
+fn flood_fill(scaled_map: &mut Grid<bool>, pos: (usize, usize)) {
+    let mut frontier = vec![pos];
+    scaled_map[pos] = true;
+    loop {
+        let mut expanse = Vec::new();     
+
+        for pos in &frontier {
+            let next: Vec<(usize, usize)> = scaled_map
+                .neighbors4(*pos)
+                .filter(|n| !scaled_map[*n])
+                .collect();
+            for n in next {
+                scaled_map[n] = true;
+                expanse.push(n);
+            }
+        }
+        if expanse.is_empty() {
+            break;
+        }
+        frontier = expanse;
+    }
+}
+
+fn count_enclosed(map: &Map) -> usize {
+    let (pipe_map, _) = map.bfs();
+
+    let mut scaled_map = scale_up(&pipe_map, map);
+    let (n, m) = (scaled_map.height(), scaled_map.width());
+    let flood_start: Vec<(usize, usize)> = scaled_map
+        .positions()
+        .filter(|&(i, j)| i == 0 || j == 0 || i == n - 1 || j == m - 1)
+        .collect();
+
+    for pos in flood_start {
+        if !scaled_map[pos] {
+            trace!("start flood fill from {:?}", pos);
+            flood_fill(&mut scaled_map, pos);
+        }
+    }
+
+    // evry point that is marked in scaled_map after the flood is 
+    // guaranteed to be outside of the loop, to "scale down" just 
+    // check for any point in the normal sized map if its pendant 
+    // in the scaled map is outside of the loop. What's left is 
+    // inside.
+      
+    pipe_map
+        .positions()
+        .filter(|&(i, j)| !scaled_map[(2 * i, 2 * j)])
+        .count()
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Map;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        Map::parse(buf)
+    }
+
+    fn part1(&self, map: &Self::Input) -> aoc_core::Result<usize> {
+        let (_, iterations) = map.bfs();
+        Ok(iterations)
+    }
+
+    fn part2(&self, map: &Self::Input) -> aoc_core::Result<usize> {
+        Ok(count_enclosed(map))
+    }
+
+    fn print(&self, map: &Self::Input) -> Option<String> {
+        Some(format!("{}\n", map.tiles))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
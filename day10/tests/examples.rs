@@ -0,0 +1,31 @@
+use aoc_core::Solver;
+use day10::Day10;
+
+fn solve(buf: &str, part: u32) -> usize {
+    let input = Day10.parse(buf).unwrap();
+    match part {
+        1 => Day10.part1(&input).unwrap(),
+        _ => Day10.part2(&input).unwrap(),
+    }
+}
+
+#[test]
+fn part1_examples() {
+    assert_eq!(solve(include_str!("example1.txt"), 1), 4);
+    assert_eq!(solve(include_str!("example2.txt"), 1), 8);
+}
+
+#[test]
+fn part2_examples() {
+    assert_eq!(solve(include_str!("example3.txt"), 2), 4);
+    assert_eq!(solve(include_str!("example4.txt"), 2), 8);
+    assert_eq!(solve(include_str!("example5.txt"), 2), 10);
+}
+
+#[test]
+fn malformed_maps_are_parse_errors() {
+    let err = Day10.parse(".F7\n.LJ\n").unwrap_err();
+    assert!(err.to_string().contains("expected a start tile 'S' somewhere in the map"), "{}", err);
+    let err = Day10.parse("S-7\n|.X\n").unwrap_err();
+    assert!(err.to_string().starts_with("line 2, column 3: expected a tile"), "{}", err);
+}
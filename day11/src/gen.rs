@@ -0,0 +1,13 @@
+use aoc_core::rng::Rng;
+
+// a size x size image with about one galaxy in 50 cells, which leaves a few
+// empty rows and columns to expand
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let row: String = (0..size).map(|_| if rng.chance(1, 50) { '#' } else { '.' }).collect();
+        buf.push_str(&row);
+        buf.push('\n');
+    }
+    buf
+}
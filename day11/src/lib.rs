@@ -0,0 +1,135 @@
+use aoc_core::math::overflow;
+use aoc_core::parse::parse_all;
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+fn parse_galaxies(fbuf: &str) -> aoc_core::Result<Vec<(i64, i64)>> {
+    let image = parse_all(fbuf, |p| {
+        p.lines(|p| p.cells("'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }))
+    })?;
+    Ok(image.iter().enumerate().flat_map(|(i, row)| {
+        row
+            .iter()
+            .enumerate()
+            .filter(|(_, galaxy)| **galaxy)
+            .map( move |(j, _)| (i as i64, j as i64))
+    }).collect())
+}
+
+fn expand(galaxies: &[(i64, i64)], expansion_factor: i64) -> Vec<(i64, i64)> {
+    let mut data = galaxies.to_vec();
+
+    let (Some(x_max), Some(y_max)) = (data.iter().map(|p| p.0).max(), data.iter().map(|p| p.1).max()) else {
+        return data;
+    };
+
+    // every empty row or column is replaced by expansion_factor many, so it
+    // adds expansion_factor - 1 to the coordinates behind it
+    let expansion_factor = expansion_factor - 1;
+
+    let mut empty_rows = vec![expansion_factor; x_max as usize + 1];
+    let mut empty_cols = vec![expansion_factor; y_max as usize + 1];
+    for (x, y) in data.iter() {
+        empty_rows[*x as usize] = 0;
+        empty_cols[*y as usize] = 0;
+    } 
+
+    for i in 0..empty_rows.len() - 1 {
+        empty_rows[i + 1] += empty_rows[i];
+    }
+    
+    for i in 0..empty_cols.len() - 1 {
+        empty_cols[i + 1] += empty_cols[i];
+    }
+    
+    for i in 0..data.len() {
+        data[i].0 += empty_rows[data[i].0 as usize];
+        data[i].1 += empty_cols[data[i].1 as usize];
+    } 
+    data
+}
+
+// every pair on its own
+fn sum_of_distances_pairwise(galaxies: &[(i64, i64)], expansion_factor: i64) -> aoc_core::Result<i64> {
+    let data = expand(galaxies, expansion_factor);
+    let mut sum = 0i64;
+    for i in 0..data.len() {
+        for j in 0..data.len() {
+            let v = data[i];
+            let w = data[j];
+            let d = (v.0 - w.0).abs() + (v.1 - w.1).abs();
+            sum = sum.checked_add(d).ok_or_else(overflow::<i64>)?;
+        }
+    }
+
+    Ok(sum / 2)
+}
+
+// The distance is the sum of the distances along both axes, which can be
+// added up separately. Along one axis, sorted, the k-th coordinate is
+// subtracted from all after it and added to all before it.
+fn axis_sum(mut coords: Vec<i64>) -> Option<i64> {
+    coords.sort_unstable();
+    let n = coords.len() as i64;
+    coords
+        .iter()
+        .enumerate()
+        .try_fold(0i64, |acc, (k, c)| acc.checked_add(c.checked_mul(2 * k as i64 - n + 1)?))
+}
+
+fn sum_of_distances(galaxies: &[(i64, i64)], expansion_factor: i64) -> aoc_core::Result<i64> {
+    let data = expand(galaxies, expansion_factor);
+    let xs = axis_sum(data.iter().map(|p| p.0).collect());
+    let ys = axis_sum(data.iter().map(|p| p.1).collect());
+    xs.zip(ys).and_then(|(x, y)| x.checked_add(y)).ok_or_else(overflow::<i64>)
+}
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<(i64, i64)>;
+    type Answer = i64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_galaxies(buf)
+    }
+
+    fn part1(&self, galaxies: &Self::Input) -> aoc_core::Result<i64> {
+        sum_of_distances(galaxies, 2)
+    }
+
+    fn part2(&self, galaxies: &Self::Input) -> aoc_core::Result<i64> {
+        sum_of_distances(galaxies, 1000000)
+    }
+
+    fn reference(&self, part: u32, galaxies: &Self::Input) -> Option<aoc_core::Result<i64>> {
+        let expansion_factor = if part == 1 { 2 } else { 1000000 };
+        Some(sum_of_distances_pairwise(galaxies, expansion_factor))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_of_distances_grows_with_the_expansion() {
+        let galaxies = parse_galaxies(include_str!("../tests/example.txt")).unwrap();
+        assert_eq!(sum_of_distances(&galaxies, 2).unwrap(), 374);
+        assert_eq!(sum_of_distances(&galaxies, 10).unwrap(), 1030);
+        assert_eq!(sum_of_distances(&galaxies, 100).unwrap(), 8410);
+        assert_eq!(sum_of_distances(&[], 2).unwrap(), 0);
+        assert_eq!(sum_of_distances_pairwise(&galaxies, 100).unwrap(), 8410);
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day11::Day11;
+
+#[test]
+fn part1_example() {
+    let input = Day11.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day11.part1(&input).unwrap(), 374);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day11.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day11.part2(&input).unwrap(), 82000210);
+}
@@ -0,0 +1,33 @@
+use aoc_core::rng::Rng;
+
+// size rows of up to 20 springs. Each row starts out as a real arrangement of
+// its groups, then about half of the springs are covered by '?', so there is
+// at least one arrangement that fits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let len = rng.between(4, 20);
+        let mut springs = String::new();
+        let mut groups: Vec<String> = Vec::new();
+        while springs.len() < len {
+            let group = rng.between(1, 5).min(len - springs.len());
+            if rng.chance(1, 3) {
+                springs.push_str(&"#".repeat(group));
+                groups.push(group.to_string());
+                if springs.len() < len {
+                    springs.push('.');
+                }
+            } else {
+                springs.push('.');
+            }
+        }
+        if groups.is_empty() {
+            let at = rng.below(len);
+            springs.replace_range(at..at + 1, "#");
+            groups.push("1".to_string());
+        }
+        let covered: String = springs.chars().map(|c| if rng.chance(1, 2) { '?' } else { c }).collect();
+        buf.push_str(&format!("{} {}\n", covered, groups.join(",")));
+    }
+    buf
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use aoc_core::math::overflow;
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::{trace, Solver};
+
+mod gen;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum SpringState {
+    Broken = b'#',
+    Functional = b'.',
+    Unknown = b'?',
+}
+
+impl SpringState {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(SpringState::Broken),
+            '.' => Some(SpringState::Functional),
+            '?' => Some(SpringState::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl From<SpringState> for char {
+    fn from(val: SpringState) -> Self {
+        val as u8 as char
+    }
+}
+
+fn count_functionals(xs: &[SpringState]) -> usize {
+    xs.iter().filter(|s| **s == SpringState::Functional).count()
+}
+
+fn count_brokens(xs: &[SpringState]) -> usize {
+    xs.iter().filter(|s| **s == SpringState::Broken).count()
+}
+
+fn valid_grouping(xs: &[SpringState], n: u32) -> bool {
+    let n = n as usize;
+    if n > xs.len() {
+        false
+    } else if n == xs.len() {
+        count_functionals(&xs[0..n]) == 0
+    } else { // n < xs.len()
+        (count_functionals(&xs[0..n]) == 0) && xs[n] != SpringState::Broken
+    }
+}
+
+fn cache_key(xs: &[SpringState], ns: &[u32]) -> (String, String) {
+    let xk: String = xs.iter().map(|&s| -> char {s.into()}).collect();
+    let mut nk = String::new();
+    for n in ns {
+        let tmp = n.to_string();
+        nk.push_str(&tmp);
+    }
+    (xk, nk)
+}
+
+// None if the count overflows
+fn solve(xs: &[SpringState], ns: &[u32], cache: &mut HashMap<(String, String), usize>) -> Option<usize> {
+    if xs.is_empty() {
+        if ns.is_empty() {
+            return Some(1);
+        } else {
+            return Some(0);
+        }
+    }
+
+    if ns.is_empty() {
+        if count_brokens(xs) == 0 {
+            return Some(1);
+        } else {
+            return Some(0);
+        }
+    }
+
+    let ckey = cache_key(xs, ns);
+    if let Some(count) = cache.get(&ckey) {
+        return Some(*count);
+    }
+
+    let mut count = 0usize;
+
+    // handle ? as .
+    if xs[0] == SpringState::Functional || xs[0] == SpringState::Unknown {
+        count = count.checked_add(solve(&xs[1..], ns, cache)?)?;
+    }
+
+    // handle ? as #
+    if xs[0] == SpringState::Broken || xs[0] == SpringState::Unknown {
+        let n = ns[0];
+        if valid_grouping(xs, n) {
+            if (n as usize) == xs.len() { 
+                count = count.checked_add(solve(&xs[n as usize..], &ns[1..], cache)?)?;
+            } else { 
+                // we skip the next symbol after the grouping because 
+                // it has to be '.' or '?' which is mapped to '.' in that case
+                count = count.checked_add(solve(&xs[n as usize + 1..], &ns[1..], cache)?)?;
+            }
+        }
+    }
+    cache.insert(ckey, count);
+    Some(count)
+}
+
+fn unfold_springs(xs: &[SpringState]) -> Vec<SpringState> {
+    let mut res = Vec::with_capacity(5 * xs.len());
+    for i in 0..5 {
+        if i > 0 {
+            res.push(SpringState::Unknown);
+        }
+        res.extend(xs.iter().copied());
+    } 
+    res
+}
+
+fn unfold_numbers(ns: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(5 * ns.len());
+    for _ in 0..5 {
+        res.extend(ns.iter());
+    }
+    res
+}
+
+fn parse_line(p: &mut Parser) -> aoc_core::Result<Record> {
+    let xs = p.many(|p| p.token("a spring", SpringState::from_char))?;
+    let ns = p.sep_by(",", |p| p.number::<u32>())?;
+    Ok((xs, ns))
+}
+
+type Record = (Vec<SpringState>, Vec<u32>);
+
+fn count_arrangements(records: &[Record], unfold: bool) -> aoc_core::Result<usize> {
+    let mut cnt = 0usize;
+    for (no, (xs, ns)) in records.iter().enumerate() {
+        let (xs, ns) = if unfold {
+            (unfold_springs(xs), unfold_numbers(ns))
+        } else {
+            (xs.clone(), ns.clone())
+        };
+
+        let mut cache: HashMap<(String, String), usize> = HashMap::new();
+        let arrangements = solve(&xs, &ns, &mut cache).ok_or_else(overflow::<usize>)?;
+        cnt = cnt.checked_add(arrangements).ok_or_else(overflow::<usize>)?;
+        trace!("record {}: {} arrangements, {} in total", no + 1, arrangements, cnt);
+    }
+    Ok(cnt)
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, |p| p.lines(parse_line))
+    }
+
+    fn part1(&self, records: &Self::Input) -> aoc_core::Result<usize> {
+        count_arrangements(records, false)
+    }
+
+    fn part2(&self, records: &Self::Input) -> aoc_core::Result<usize> {
+        count_arrangements(records, true)
+    }
+
+    fn print(&self, records: &Self::Input) -> Option<String> {
+        let lines = records.iter().map(|(xs, ns)| {
+            let springs: String = xs.iter().map(|&x| char::from(x)).collect();
+            let groups: Vec<String> = ns.iter().map(|n| n.to_string()).collect();
+            format!("{} {}\n", springs, groups.join(","))
+        });
+        Some(lines.collect())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arrangements(line: &str, unfold: bool) -> usize {
+        let record = parse_all(line, |p| p.lines(parse_line)).unwrap();
+        count_arrangements(&record, unfold).unwrap()
+    }
+
+    #[test]
+    fn solve_counts_every_arrangement() {
+        let springs: Vec<SpringState> = "???.###".chars().map(|c| SpringState::from_char(c).unwrap()).collect();
+        assert_eq!(solve(&springs, &[1, 1, 3], &mut HashMap::new()), Some(1));
+        assert_eq!(arrangements(".??..??...?##. 1,1,3", false), 4);
+        assert_eq!(arrangements("?#?#?#?#?#?#?#? 1,3,1,6", false), 1);
+        assert_eq!(arrangements("????.#...#... 4,1,1", false), 1);
+        assert_eq!(arrangements("????.######..#####. 1,6,5", false), 4);
+        assert_eq!(arrangements("?###???????? 3,2,1", false), 10);
+    }
+
+    #[test]
+    fn solve_unfolded() {
+        assert_eq!(arrangements("???.### 1,1,3", true), 1);
+        assert_eq!(arrangements(".??..??...?##. 1,1,3", true), 16384);
+        assert_eq!(arrangements("????.#...#... 4,1,1", true), 16);
+        assert_eq!(arrangements("?###???????? 3,2,1", true), 506250);
+    }
+
+    #[test]
+    fn solve_edge_cases() {
+        assert_eq!(solve(&[], &[], &mut HashMap::new()), Some(1));
+        assert_eq!(solve(&[], &[1], &mut HashMap::new()), Some(0));
+        assert_eq!(solve(&[SpringState::Broken], &[], &mut HashMap::new()), Some(0));
+        assert_eq!(solve(&[SpringState::Unknown], &[2], &mut HashMap::new()), Some(0));
+    }
+}
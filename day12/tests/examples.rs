@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day12::Day12;
+
+#[test]
+fn part1_example() {
+    let input = Day12.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day12.part1(&input).unwrap(), 21);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day12.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day12.part2(&input).unwrap(), 525152);
+}
@@ -0,0 +1,109 @@
+use aoc_core::grid::Grid;
+use aoc_core::rng::Rng;
+use aoc_core::union_find::UnionFind;
+
+use crate::{axes, mirrored, mismatches};
+
+// A pattern that reflects perfectly across exactly one axis and is off by a
+// single cell across exactly one other, so there is exactly one smudge.
+//
+// The cells are first filled so that both axes reflect, then one cell that
+// only the second axis maps somewhere is flipped. Patterns where some other
+// axis happens to (almost) reflect as well are thrown away.
+fn pattern(rng: &mut Rng) -> Grid<bool> {
+    loop {
+        let mut pattern = Grid::new(rng.between(5, 17), rng.between(5, 17), false);
+        let all = axes(&pattern);
+        let clean = *rng.pick(&all);
+        let smudged = *rng.pick(&all);
+        if clean == smudged {
+            continue;
+        }
+
+        let width = pattern.width();
+        let index = |(i, j): (usize, usize)| i * width + j;
+        let mut sets = UnionFind::new(width * pattern.height());
+        for (p, q) in mirrored(&pattern, clean).into_iter().chain(mirrored(&pattern, smudged)) {
+            sets.union(index(p), index(q));
+        }
+        let mut colors = vec![false; width * pattern.height()];
+        for c in colors.iter_mut() {
+            *c = rng.chance(1, 2);
+        }
+        for pos in pattern.positions() {
+            let root = sets.find(index(pos));
+            pattern[pos] = colors[root];
+        }
+
+        let mut fixed = Grid::new(width, pattern.height(), false);
+        for (p, q) in mirrored(&pattern, clean) {
+            fixed[p] = true;
+            fixed[q] = true;
+        }
+        let candidates: Vec<(usize, usize)> = mirrored(&pattern, smudged)
+            .into_iter()
+            .flat_map(|(p, q)| [p, q])
+            .filter(|&pos| !fixed[pos])
+            .collect();
+        if candidates.is_empty() {
+            continue;
+        }
+        let smudge = *rng.pick(&candidates);
+        pattern[smudge] = !pattern[smudge];
+
+        let unique = all.iter().all(|&axis| {
+            let expected = if axis == clean {
+                0..=0
+            } else if axis == smudged {
+                1..=1
+            } else {
+                2..=usize::MAX
+            };
+            expected.contains(&mismatches(&pattern, axis))
+        });
+        if unique {
+            return pattern;
+        }
+    }
+}
+
+// size patterns of 5 to 17 rows and columns, separated by blank lines
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size)
+        .map(|_| pattern(rng).map(|&rock| if rock { '#' } else { '.' }).to_string())
+        .collect();
+    let mut buf = patterns.join("\n\n");
+    buf.push('\n');
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solve, solve_smudged};
+
+    #[test]
+    fn there_is_exactly_one_smudge() {
+        let mut rng = Rng::new(1);
+        for _ in 0..20 {
+            let mut p = pattern(&mut rng);
+            let clean = solve(&p, 0);
+            assert!(clean > 0);
+            // flipping the smudge or the cell it is mirrored onto, both give
+            // the same new axis
+            let mut axes: Vec<usize> = p
+                .positions()
+                .map(|pos| {
+                    let mut q = p.clone();
+                    q[pos] = !q[pos];
+                    solve(&q, clean)
+                })
+                .filter(|&axis| axis > 0)
+                .collect();
+            assert!((1..=2).contains(&axes.len()));
+            axes.dedup();
+            assert_eq!(axes.len(), 1);
+            assert!(solve_smudged(&mut p) > 0);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_core::grid::Grid;
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::{trace, Solver};
+
+mod gen;
+
+fn calc_horizontal_reflection(pattern: &Grid<bool>, axis: usize) -> usize {
+    let m = pattern.width();
+    let mut refwidth = 0;
+    for j in axis..m {
+        if axis < (j - axis) + 1 {
+            continue;
+        }
+        let rj = axis - (j - axis) - 1;
+        if !pattern.col(j).eq(pattern.col(rj)) {
+            return refwidth;
+        }
+        refwidth += 1
+    }
+    refwidth
+}
+
+fn calc_vertical_reflection(pattern: &Grid<bool>, axis: usize) -> usize {
+    let n = pattern.height();
+    let mut refwidth = 0;
+    for i in axis..n {
+        if axis < (i - axis) + 1 {
+            continue;
+        }
+        let ri = axis - (i - axis) - 1;
+        if pattern.row(i) != pattern.row(ri) {
+            return refwidth;
+        }
+        refwidth += 1;
+    }
+    refwidth
+}
+
+fn solve(pattern: &Grid<bool>, base_axis: usize) -> usize {
+    let n = pattern.height();
+    let m = pattern.width();
+
+    for axis in 1..m {
+        let h = calc_horizontal_reflection(pattern, axis);
+        if h == 0 || axis == base_axis {
+            continue;
+        }
+        if axis + h == m || axis - h == 0 {
+            return axis;
+        }
+    }
+
+    for axis in 1..n {
+        let v = calc_vertical_reflection(pattern, axis);
+        if v == 0 || 100 * axis == base_axis {
+            continue;
+        }
+        trace!("rows mirror around {} for {} rows", axis, v);
+        if axis + v == n || axis - v == 0 {
+            return 100 * axis;
+        }
+    }
+    0
+}
+
+fn parse_pattern(p: &mut Parser) -> aoc_core::Result<Grid<bool>> {
+    p.grid_with("'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+// patterns are separated by blank lines
+fn parse_patterns(fbuf: &str) -> aoc_core::Result<Vec<Grid<bool>>> {
+    parse_all(fbuf, |p| {
+        let mut patterns = Vec::new();
+        loop {
+            p.skip_blank_lines();
+            let pattern = parse_pattern(p)?;
+            if pattern.is_empty() {
+                break;
+            }
+            patterns.push(pattern);
+        }
+        Ok(patterns)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    // between column a - 1 and a
+    Col(usize),
+    // between row a - 1 and a
+    Row(usize),
+}
+
+impl Axis {
+    // what the puzzle makes of the axis
+    fn summary(self) -> usize {
+        match self {
+            Axis::Col(a) => a,
+            Axis::Row(a) => 100 * a,
+        }
+    }
+}
+
+// columns first, the same order solve() tries them in
+fn axes(pattern: &Grid<bool>) -> Vec<Axis> {
+    let cols = (1..pattern.width()).map(Axis::Col);
+    cols.chain((1..pattern.height()).map(Axis::Row)).collect()
+}
+
+// the cells the axis maps onto each other
+fn mirrored(pattern: &Grid<bool>, axis: Axis) -> Vec<((usize, usize), (usize, usize))> {
+    let (n, m) = (pattern.height(), pattern.width());
+    match axis {
+        Axis::Col(a) => (0..n)
+            .flat_map(|i| (0..a.min(m - a)).map(move |k| ((i, a - 1 - k), (i, a + k))))
+            .collect(),
+        Axis::Row(a) => (0..a.min(n - a))
+            .flat_map(|k| (0..m).map(move |j| ((a - 1 - k, j), (a + k, j))))
+            .collect(),
+    }
+}
+
+fn mismatches(pattern: &Grid<bool>, axis: Axis) -> usize {
+    mirrored(pattern, axis).into_iter().filter(|&(p, q)| pattern[p] != pattern[q]).count()
+}
+
+// Fixing the smudge makes an axis reflect that was off by exactly one cell
+// before, no need to try flipping every cell.
+fn smudged_axis(pattern: &Grid<bool>) -> usize {
+    axes(pattern)
+        .into_iter()
+        .find(|&axis| mismatches(pattern, axis) == 1)
+        .map_or(0, Axis::summary)
+}
+
+// flips every cell in turn until another axis reflects
+fn solve_smudged(pattern: &mut Grid<bool>) -> usize {
+    let base_axis = solve(pattern, 0);
+    for pos in pattern.positions() {
+        pattern[pos] = !pattern[pos];
+        let axis = solve(pattern, base_axis);
+        pattern[pos] = !pattern[pos];
+        if axis > 0 {
+            return axis;
+        }
+    }
+    0
+}
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Grid<bool>>;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_patterns(buf)
+    }
+
+    fn part1(&self, patterns: &Self::Input) -> aoc_core::Result<usize> {
+        Ok(patterns.iter().map(|pattern| solve(pattern, 0)).sum())
+    }
+
+    fn part2(&self, patterns: &Self::Input) -> aoc_core::Result<usize> {
+        Ok(patterns.iter().map(smudged_axis).sum())
+    }
+
+    fn reference(&self, part: u32, patterns: &Self::Input) -> Option<aoc_core::Result<usize>> {
+        if part != 2 {
+            return None;
+        }
+        let mut patterns = patterns.clone();
+        Some(Ok(patterns.iter_mut().map(solve_smudged).sum()))
+    }
+
+    fn print(&self, patterns: &Self::Input) -> Option<String> {
+        let patterns: Vec<String> = patterns
+            .iter()
+            .map(|pattern| pattern.map(|&rock| if rock { '#' } else { '.' }).to_string() + "\n")
+            .collect();
+        Some(patterns.join("\n"))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<Grid<bool>> {
+        parse_patterns(include_str!("../tests/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn solve_finds_vertical_and_horizontal_mirrors() {
+        let patterns = example();
+        assert_eq!(solve(&patterns[0], 0), 5);
+        assert_eq!(solve(&patterns[1], 0), 400);
+    }
+
+    #[test]
+    fn solve_skips_the_base_axis() {
+        let patterns = example();
+        assert_eq!(solve(&patterns[0], 5), 0);
+        assert_eq!(solve(&patterns[1], 400), 0);
+    }
+
+    #[test]
+    fn solve_smudged_finds_the_new_mirror() {
+        let mut patterns = example();
+        assert_eq!(solve_smudged(&mut patterns[0]), 300);
+        assert_eq!(solve_smudged(&mut patterns[1]), 100);
+        assert_eq!(smudged_axis(&patterns[0]), 300);
+        assert_eq!(smudged_axis(&patterns[1]), 100);
+        // the pattern is left as it was
+        assert_eq!(patterns, example());
+    }
+
+    #[test]
+    fn ragged_pattern_is_an_error() {
+        assert!(parse_patterns("#.#\n#.\n").is_err());
+    }
+}
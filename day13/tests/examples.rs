@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day13::Day13;
+
+#[test]
+fn part1_example() {
+    let input = Day13.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day13.part1(&input).unwrap(), 405);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day13.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day13.part2(&input).unwrap(), 400);
+}
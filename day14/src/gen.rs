@@ -0,0 +1,19 @@
+use aoc_core::rng::Rng;
+
+// a size x size platform, about a fifth of it rounded rocks and a sixth cube
+// shaped ones
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let row: String = (0..size)
+            .map(|_| match rng.below(30) {
+                0..=5 => 'O',
+                6..=10 => '#',
+                _ => '.',
+            })
+            .collect();
+        buf.push_str(&row);
+        buf.push('\n');
+    }
+    buf
+}
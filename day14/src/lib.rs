@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use aoc_core::grid::Grid;
+use aoc_core::parse::parse_all;
+use aoc_core::rng::Rng;
+use aoc_core::session::Session;
+use aoc_core::{debug, trace, Solver};
+
+mod gen;
+mod session;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum Field {
+    RoundRock = b'O',
+    SquareRock = b'#',
+    FreeSpace = b'.',
+}
+
+impl Field {
+    fn is_square(&self) -> bool {
+        *self == Field::SquareRock
+    }
+    
+    fn is_round(&self) -> bool {
+        *self == Field::RoundRock
+    }
+
+    fn is_free(&self) -> bool {
+        *self == Field::FreeSpace
+    }
+}
+
+impl From<Field> for char {
+    fn from(val: Field) -> Self {
+        val as u8 as char
+    }
+}
+
+impl Field {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(Field::RoundRock),
+            '#' => Some(Field::SquareRock),
+            '.' => Some(Field::FreeSpace),
+            _ => None,
+        }
+    }
+}
+
+fn tilt_col_north(j: usize, pattern: &mut Grid<Field>) {
+    let mut seg = 0; 
+    for i in 0..pattern.height() {
+        if pattern[(i, j)].is_square() {
+            seg = i + 1;
+        } else if pattern[(i, j)].is_round() {
+            let mut ix = i;
+            for i0 in (seg..i).rev() {
+                if !pattern[(i0, j)].is_free() {
+                    break;
+                }
+                ix = i0;
+            }
+            pattern.swap((i, j), (ix, j));
+        }
+    }
+}
+
+fn tilt_row_west(i: usize, pattern: &mut Grid<Field>) {
+    let m = pattern.width();
+    let mut seg = 0;
+    for j in 0..m {
+        if pattern[(i, j)].is_square() {
+            seg = j + 1;
+        } else if pattern[(i, j)].is_round() {
+            let mut jx = j;
+            for j0 in (seg..j).rev() {
+                if !pattern[(i, j0)].is_free() {
+                    break;
+                }
+                jx = j0;
+            }
+            pattern.swap((i, j), (i, jx));
+        }
+    } 
+}
+
+fn tilt_col_south(j: usize, pattern: &mut Grid<Field>) {
+    let n = pattern.height();
+    for i in (0..n).rev() {
+        if pattern[(i, j)].is_round() {
+            let mut ix = i;
+            for i0 in i+1..n {
+                if !pattern[(i0, j)].is_free() {
+                    break;
+                }
+                ix = i0;
+            }
+            pattern.swap((i, j), (ix, j));
+        }
+    }
+}
+
+fn tilt_row_east(i: usize, pattern: &mut Grid<Field>) {
+    let m = pattern.width();
+    for j in (0..m).rev() {
+        if pattern[(i, j)].is_round() {
+            let mut jx = j;
+            for j0 in j+1..m {
+                if !pattern[(i, j0)].is_free() {
+                    break;
+                }
+                jx = j0;
+            }
+            pattern.swap((i, j), (i, jx));
+        }
+    }
+}
+
+fn tilt_cycle(pattern: &mut Grid<Field>) {
+    let n = pattern.height();
+    let m = pattern.width();
+
+    for j in 0..m {
+        tilt_col_north(j, pattern);
+    }
+
+    for i in 0..n {
+        tilt_row_west(i, pattern);
+    }
+
+    for j in 0..m {
+        tilt_col_south(j, pattern);
+    }
+
+    for i in 0..n {
+        tilt_row_east(i, pattern);
+    }
+}
+
+
+fn calc_load(pattern: &Grid<Field>) -> usize {
+    let n = pattern.height();
+    pattern
+        .iter()
+        .filter(|(_, field)| field.is_round())
+        .map(|((i, _), _)| n - i)
+        .sum()
+}
+
+fn parse_pattern(fbuf: &str) -> aoc_core::Result<Grid<Field>> {
+    parse_all(fbuf, |p| {
+        let pattern = p.grid_with("a field", Field::from_char)?;
+        if pattern.is_empty() {
+            return Err(p.error("a row of fields"));
+        }
+        Ok(pattern)
+    })
+}
+
+// Runs the given number of spin cycles. Once the platform is back in an
+// earlier state it goes round in circles, whole rounds of those are skipped.
+fn spin(pattern: &mut Grid<Field>, cycles: usize) {
+    let mut seen: HashMap<Grid<Field>, usize> = HashMap::new();
+    let mut round = 0;
+    while round < cycles {
+        tilt_cycle(pattern);
+        round += 1;
+        trace!("after round {}, load {}:\n{}", round, calc_load(pattern), pattern);
+        if let Some(prev) = seen.insert(pattern.clone(), round) {
+            let cycle_length = round - prev;
+            debug!("round {} repeats round {}, the cycle is {} rounds long", round, prev, cycle_length);
+            round += (cycles - round) / cycle_length * cycle_length;
+            debug!("skipping ahead to round {}", round);
+            break;
+        }
+    }
+    for _ in round..cycles {
+        tilt_cycle(pattern);
+    }
+}
+
+fn load_after_cycles(pattern: &Grid<Field>) -> usize {
+    let mut pattern = pattern.clone();
+    spin(&mut pattern, 1000000000);
+    calc_load(&pattern)
+}
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Grid<Field>;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_pattern(buf)
+    }
+
+    fn part1(&self, pattern: &Self::Input) -> aoc_core::Result<usize> {
+        let mut pattern = pattern.clone();
+        for j in 0..pattern.width() {
+            tilt_col_north(j, &mut pattern);
+        }
+        Ok(calc_load(&pattern))
+    }
+
+    fn part2(&self, pattern: &Self::Input) -> aoc_core::Result<usize> {
+        Ok(load_after_cycles(pattern))
+    }
+
+    fn print(&self, pattern: &Self::Input) -> Option<String> {
+        Some(format!("{}\n", pattern))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+
+    fn session(&self, pattern: &Self::Input) -> Option<Box<dyn Session>> {
+        Some(Box::new(session::Platform::new(pattern)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<Field> {
+        parse_pattern(include_str!("../tests/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn tilt_cycle_spins_north_west_south_east() {
+        let mut pattern = example();
+        tilt_cycle(&mut pattern);
+        let after_one = "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+        assert_eq!(pattern.to_string(), after_one);
+
+        tilt_cycle(&mut pattern);
+        tilt_cycle(&mut pattern);
+        let after_three = "\
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+        assert_eq!(pattern.to_string(), after_three);
+    }
+
+    #[test]
+    fn calc_load_counts_rows_from_the_south() {
+        let mut pattern = example();
+        for j in 0..pattern.width() {
+            tilt_col_north(j, &mut pattern);
+        }
+        assert_eq!(calc_load(&pattern), 136);
+    }
+}
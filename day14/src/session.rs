@@ -0,0 +1,101 @@
+use aoc_core::grid::Grid;
+use aoc_core::session::{count_arg, Session};
+
+use crate::{calc_load, spin, tilt_col_north, tilt_col_south, tilt_row_east, tilt_row_west, Field};
+
+enum Move {
+    Cycles(usize),
+    Tilt(&'static str),
+}
+
+// The platform with its rocks moved so far, and how they got there.
+pub struct Platform {
+    start: Grid<Field>,
+    grid: Grid<Field>,
+    moves: Vec<Move>,
+}
+
+impl Platform {
+    pub fn new(grid: &Grid<Field>) -> Self {
+        Platform { start: grid.clone(), grid: grid.clone(), moves: Vec::new() }
+    }
+
+    fn status(&self) -> String {
+        let moves: Vec<String> = self
+            .moves
+            .iter()
+            .map(|m| match m {
+                Move::Cycles(1) => "1 cycle".to_string(),
+                Move::Cycles(n) => format!("{} cycles", n),
+                Move::Tilt(towards) => format!("a tilt {}", towards),
+            })
+            .collect();
+        let moves = match moves.len() {
+            0 => "0 cycles".to_string(),
+            1 => moves[0].clone(),
+            _ => moves.join(", then ") + ",",
+        };
+        format!("after {} the load on the north beams is {}", moves, calc_load(&self.grid))
+    }
+
+    fn step(&mut self, n: usize) -> String {
+        spin(&mut self.grid, n);
+        match self.moves.last_mut() {
+            Some(Move::Cycles(cycles)) => *cycles += n,
+            _ => self.moves.push(Move::Cycles(n)),
+        }
+        self.status()
+    }
+
+    fn tilt(&mut self, towards: &str) -> aoc_core::Result<String> {
+        let (n, m) = (self.grid.height(), self.grid.width());
+        let towards = match towards {
+            "north" => {
+                (0..m).for_each(|j| tilt_col_north(j, &mut self.grid));
+                "north"
+            }
+            "west" => {
+                (0..n).for_each(|i| tilt_row_west(i, &mut self.grid));
+                "west"
+            }
+            "south" => {
+                (0..m).for_each(|j| tilt_col_south(j, &mut self.grid));
+                "south"
+            }
+            "east" => {
+                (0..n).for_each(|i| tilt_row_east(i, &mut self.grid));
+                "east"
+            }
+            _ => return Err(format!("can't tilt towards {:?}, expected north, west, south or east", towards).into()),
+        };
+        self.moves.push(Move::Tilt(towards));
+        Ok(self.status())
+    }
+}
+
+impl Session for Platform {
+    fn help(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("show grid", "the platform as it is now"),
+            ("step [<n>]", "run n spin cycles, 1 by default"),
+            ("tilt north|west|south|east", "tilt the platform once"),
+            ("load", "the load on the north beams"),
+            ("reset", "back to the platform from the input"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<aoc_core::Result<String>> {
+        let out = match (command, args) {
+            ("show", ["grid"] | []) => Ok(format!("{}\n{}", self.grid, self.status())),
+            ("step", args) => count_arg(args, 1).map(|n| self.step(n)),
+            ("tilt", [towards]) => self.tilt(towards),
+            ("load", []) => Ok(self.status()),
+            ("reset", []) => {
+                *self = Platform::new(&self.start);
+                Ok(self.status())
+            }
+            _ => return None,
+        };
+        Some(out)
+    }
+}
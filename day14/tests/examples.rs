@@ -0,0 +1,42 @@
+use aoc_core::Solver;
+use day14::Day14;
+
+#[test]
+fn part1_example() {
+    let input = Day14.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day14.part1(&input).unwrap(), 136);
+}
+
+#[test]
+fn only_the_three_field_characters_parse() {
+    // 'ŏ' and 'ģ' have the same low byte as 'O' and '#'
+    for buf in ["\u{14f}.\n..\n", "..\n.\u{123}\n"] {
+        let err = Day14.parse(buf).unwrap_err();
+        assert!(err.to_string().contains("expected a field"), "{}", err);
+    }
+}
+
+#[test]
+fn part2_example() {
+    let input = Day14.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day14.part2(&input).unwrap(), 64);
+}
+
+#[test]
+fn session_steps_spin_cycles() {
+    let input = Day14.parse(include_str!("example.txt")).unwrap();
+    let mut session = Day14.session(&input).unwrap();
+    let out = session.run("step", &["3"]).unwrap().unwrap();
+    assert_eq!(out, "after 3 cycles the load on the north beams is 69");
+    assert!(session.run("tilt", &["up"]).unwrap().is_err());
+    let out = session.run("tilt", &["north"]).unwrap().unwrap();
+    assert_eq!(out, "after 3 cycles, then a tilt north, the load on the north beams is 110");
+    session.run("step", &[]).unwrap().unwrap();
+    let out = session.run("load", &[]).unwrap().unwrap();
+    assert_eq!(out, "after 3 cycles, then a tilt north, then 1 cycle, the load on the north beams is 69");
+    let out = session.run("reset", &[]).unwrap().unwrap();
+    assert_eq!(out, "after 0 cycles the load on the north beams is 104");
+    let out = session.run("step", &["1000000000"]).unwrap().unwrap();
+    assert_eq!(out, "after 1000000000 cycles the load on the north beams is 64");
+    assert!(session.run("query", &[]).is_none());
+}
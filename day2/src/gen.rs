@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use aoc_core::rng::Rng;
+
+// size games with one to six draws of up to 20 balls per colour, so that
+// some of them are possible with 12 red, 13 green and 14 blue balls
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for no in 1..=size {
+        let sets: Vec<String> = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let balls: Vec<String> = colors[..rng.between(1, 3)]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.between(1, 20), color))
+                    .collect();
+                balls.join(", ")
+            })
+            .collect();
+        let _ = writeln!(buf, "Game {}: {}", no, sets.join("; "));
+    }
+    buf
+}
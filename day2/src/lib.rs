@@ -0,0 +1,168 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_core::math::{checked_product, checked_sum};
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+//Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+
+// a colour and a number of balls of it
+type Balls = (String, u32);
+
+fn parse_ball(p: &mut Parser) -> aoc_core::Result<Balls> {
+    let count = p.number::<u32>()?;
+    // a word may be all digits, a colour starts with a letter
+    if !p.rest().trim_start_matches([' ', '\t']).starts_with(|c: char| c.is_alphabetic()) {
+        return Err(p.error("a colour"));
+    }
+    let color = p.word()?.to_string();
+    Ok((color, count))
+}
+
+#[derive(Debug)]
+pub struct Game {
+    no: u32,
+    sets: Vec<Vec<Balls>>,
+}
+
+fn parse_game(p: &mut Parser) -> aoc_core::Result<Game> {
+    p.literal("Game")?;
+    let no = p.number::<u32>()?;
+    p.literal(":")?;
+    let sets = p.sep_by(";", |p| p.sep_by(",", parse_ball))?;
+    Ok(Game{no, sets})
+}
+
+// How many balls of which colour are in the bag, a colour the bag doesn't
+// name has no balls at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    limits: Vec<Balls>,
+}
+
+impl Bag {
+    pub fn new(limits: Vec<Balls>) -> aoc_core::Result<Self> {
+        if limits.is_empty() {
+            return Err("the bag has no colours".into());
+        }
+        for (i, (color, _)) in limits.iter().enumerate() {
+            if limits[..i].iter().any(|(other, _)| other == color) {
+                return Err(format!("{} is in the bag twice", color).into());
+            }
+        }
+        Ok(Bag { limits })
+    }
+
+    // "12 red, 13 green, 14 blue", the colours can be spread over lines
+    pub fn parse(spec: &str) -> aoc_core::Result<Self> {
+        let lines = parse_all(spec, |p| {
+            p.skip_blank_lines();
+            p.lines(|p| p.sep_by(",", parse_ball))
+        })?;
+        Self::new(lines.into_iter().flatten().collect())
+    }
+
+    pub fn load(path: &Path) -> aoc_core::Result<Self> {
+        let spec = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Self::parse(&spec).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+
+    fn limit(&self, color: &str) -> u32 {
+        self.limits.iter().find(|(c, _)| c == color).map_or(0, |(_, n)| *n)
+    }
+}
+
+// the bag of the puzzle
+impl Default for Bag {
+    fn default() -> Self {
+        let limits = [("red", 12), ("green", 13), ("blue", 14)];
+        Bag { limits: limits.iter().map(|(color, n)| (color.to_string(), *n)).collect() }
+    }
+}
+
+impl Game {
+    fn possible(&self, bag: &Bag) -> bool {
+        self.sets.iter().flatten().all(|(color, cnt)| *cnt <= bag.limit(color))
+    }
+
+    // The fewest balls of each colour the game could have been played with,
+    // for every colour of the bag (possibly none of it) and any other colour
+    // the game shows.
+    fn fewest<'a>(&'a self, bag: &'a Bag) -> Vec<(&'a str, u32)> {
+        let mut fewest: Vec<(&str, u32)> = bag.limits.iter().map(|(color, _)| (color.as_str(), 0)).collect();
+        for (color, cnt) in self.sets.iter().flatten() {
+            match fewest.iter_mut().find(|(c, _)| c == color) {
+                Some((_, max)) => *max = std::cmp::max(*max, *cnt),
+                None => fewest.push((color, *cnt)),
+            }
+        }
+        fewest
+    }
+}
+
+// The bag is the one of the puzzle unless configured otherwise.
+#[derive(Default)]
+pub struct Day2 {
+    bag: Bag,
+}
+
+impl Day2 {
+    pub fn with_bag(bag: Bag) -> Self {
+        Day2 { bag }
+    }
+}
+
+impl Solver for Day2 {
+    type Input = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, |p| p.lines(parse_game))
+    }
+
+    fn part1(&self, games: &Self::Input) -> aoc_core::Result<u64> {
+        checked_sum(games.iter().filter(|game| game.possible(&self.bag)).map(|game| game.no as u64))
+    }
+
+    fn part2(&self, games: &Self::Input) -> aoc_core::Result<u64> {
+        let mut powers = Vec::new();
+        for game in games {
+            powers.push(checked_product(game.fewest(&self.bag).iter().map(|(_, cnt)| *cnt as u64))?);
+        }
+        checked_sum(powers)
+    }
+
+    fn print(&self, games: &Self::Input) -> Option<String> {
+        let mut buf = String::new();
+        for game in games {
+            let sets: Vec<String> = game
+                .sets
+                .iter()
+                .map(|set| {
+                    let balls: Vec<String> = set.iter().map(|(color, n)| format!("{} {}", n, color)).collect();
+                    balls.join(", ")
+                })
+                .collect();
+            buf.push_str(&format!("Game {}: {}\n", game.no, sets.join("; ")));
+        }
+        Some(buf)
+    }
+
+    // bag=<spec> like bag=12 red, 13 green, 14 blue or bag-file=<path>
+    fn configure(&mut self, name: &str, value: &str) -> aoc_core::Result<()> {
+        self.bag = match name {
+            "bag" => Bag::parse(value)?,
+            "bag-file" => Bag::load(Path::new(value))?,
+            _ => return Err(format!("day 2 has no option {:?}, there are bag and bag-file", name).into()),
+        };
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_core::Solver;
+use day2::{Bag, Day2};
+
+#[test]
+fn part1_example() {
+    let day = Day2::default();
+    let input = day.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(day.part1(&input).unwrap(), 8);
+}
+
+#[test]
+fn part2_example() {
+    let day = Day2::default();
+    let input = day.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 2286);
+}
+
+#[test]
+fn any_colours_with_a_configured_bag() {
+    let day = Day2::with_bag(Bag::parse("2 cyan, 4 magenta\n1 red\n").unwrap());
+    let input = day.parse("Game 1: 2 cyan, 1 red\nGame 2: 4 magenta; 3 cyan\nGame 3: 1 yellow\n").unwrap();
+    assert_eq!(day.part1(&input).unwrap(), 1);
+    // the bag's colours a game doesn't show count as none
+    assert_eq!(day.part2(&input).unwrap(), 0);
+    let day = Day2::with_bag(Bag::parse("1 cyan").unwrap());
+    assert_eq!(day.part2(&input).unwrap(), 2 + 3 * 4);
+
+    assert!(Bag::parse("1 red, 2 red").is_err());
+    assert!(Bag::parse("").is_err());
+    assert!(Bag::parse("red 1").is_err());
+}
+
+#[test]
+fn colours_start_with_a_letter() {
+    let err = Day2::default().parse("Game 1: 3 4\n").unwrap_err();
+    assert!(err.to_string().starts_with("line 1, column 11: expected a colour, found '4'"), "{}", err);
+    assert!(Day2::default().parse("Game 1: 3 4, 1 red\n").is_err());
+    assert!(Bag::parse("1 2").is_err());
+    assert!(Bag::parse("1 x2").is_ok());
+    let day = Day2::with_bag(Bag::parse("2 grün, 1 ЖЁЛТЫЙ").unwrap());
+    assert_eq!(day.part1(&day.parse("Game 1: 2 grün, 1 ЖЁЛТЫЙ\n").unwrap()).unwrap(), 1);
+}
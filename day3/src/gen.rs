@@ -0,0 +1,28 @@
+use aoc_core::rng::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+// a size x size schematic, numbers in a row are always separated by at least
+// one other cell
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let len = rng.between(1, 3);
+            if rng.chance(1, 6) && row.len() + len <= size {
+                row.push_str(&rng.between(10usize.pow(len as u32 - 1), 10usize.pow(len as u32) - 1).to_string());
+                if row.len() < size {
+                    row.push('.');
+                }
+            } else if rng.chance(1, 10) {
+                row.push(*rng.pick(&SYMBOLS));
+            } else {
+                row.push('.');
+            }
+        }
+        buf.push_str(&row);
+        buf.push('\n');
+    }
+    buf
+}
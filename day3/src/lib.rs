@@ -0,0 +1,146 @@
+use std::iter::Peekable;
+
+use aoc_core::grid::Grid;
+use aoc_core::math::checked_sum;
+use aoc_core::parse::{parse_all, ParseError};
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+struct DigitGroup<Iter: Iterator<Item = (usize, u32)>> {
+    iter: Peekable<Iter>
+}
+
+impl<Iter: Iterator<Item = (usize, u32)>> DigitGroup<Iter> {
+    fn new(iter: Iter) -> Self {
+        Self { iter: iter.peekable() }
+    }
+}
+
+impl<Iter: Iterator<Item = (usize, u32)>> Iterator for DigitGroup<Iter> {
+    // start and end of the digit run, the value is None if it overflows
+    type Item = (usize, usize, Option<u32>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut digits: Vec<u32> = Vec::new();
+        let (mut ipos, dig) = self.iter.next()?;
+        let start_pos = ipos;
+        digits.push(dig);
+
+        while let Some((pos, dig)) = self.iter.peek() {
+            if *pos == ipos + 1 {
+                ipos = *pos;
+                digits.push(*dig);
+                self.iter.next()?;
+            } else {
+                break;
+            }
+        }
+
+        let value = digits
+            .iter()
+            .try_fold(0u32, |acc, d| acc.checked_mul(10)?.checked_add(*d));
+
+        Some((start_pos, ipos + 1, value))
+    }
+}
+
+pub struct Schematic {
+    // index into values for every cell that is part of a number, 0 otherwise
+    numbers: Grid<usize>,
+    values: Vec<u32>,
+    symbols: Vec<(char, (usize, usize))>,
+}
+
+impl Schematic {
+    fn parse(buf: &str) -> aoc_core::Result<Self> {
+        let cells: Grid<char> = parse_all(buf, |p| p.grid("a cell"))?;
+
+        let mut values: Vec<u32> = Vec::new();
+        values.push(0);
+
+        let mut numbers = Grid::new(cells.width(), cells.height(), 0usize);
+        let mut symbols: Vec<(char, (usize, usize))> = Vec::new();
+
+        for (i, row) in cells.rows().enumerate() {
+            let it = row
+                .iter()
+                .enumerate()
+                .filter_map(|(j, c)| Some((j, c.to_digit(10)?)));
+            
+            let it = DigitGroup::new(it);
+            for group in it {
+                let (start, end, value) = group;
+                let value = value
+                    .ok_or_else(|| Box::new(ParseError::at_cell(buf, (i, start), "a number that fits into u32")))?;
+                values.push(value);
+                let idx = values.len() - 1;
+                for j in start..end {
+                    numbers[(i, j)] = idx;
+                }
+            }
+
+            let it = row
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| !c.is_ascii_digit() && !c.is_whitespace() && **c != '.')
+                .map(|(j, c)| (*c, (i, j)));
+            symbols.extend(it);
+        }
+        Ok(Self { numbers, values, symbols })
+    }
+
+    // indices of all numbers adjacent to coord
+    fn adjacent(&self, coord: (usize, usize)) -> Vec<usize> {
+        let mut idxs = self.numbers
+            .neighbors8(coord)
+            .map(|pos| self.numbers[pos])
+            .filter(|idx| *idx > 0)
+            .collect::<Vec<usize>>();
+        idxs.sort();
+        idxs.dedup();
+        idxs
+    }
+}
+
+#[derive(Default)]
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = Schematic;
+    type Answer = u64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        Schematic::parse(buf)
+    }
+
+    fn part1(&self, schematic: &Self::Input) -> aoc_core::Result<u64> {
+        let mut parts: Vec<usize> = schematic.symbols
+            .iter()
+            .flat_map(|(_, coord)| schematic.adjacent(*coord))
+            .collect();
+        parts.sort();
+        parts.dedup();
+        checked_sum(parts.iter().map(|idx| schematic.values[*idx] as u64))
+    }
+
+    fn part2(&self, schematic: &Self::Input) -> aoc_core::Result<u64> {
+        let ratios = schematic.symbols
+            .iter()
+            .filter(|(sym, _)| *sym == '*')
+            .map(|(_, coord)| {
+                let vals = schematic.adjacent(*coord);
+                if vals.len() == 2 {
+                    schematic.values[vals[0]] as u64 * schematic.values[vals[1]] as u64
+                } else {
+                    0
+                }
+            });
+        checked_sum(ratios)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
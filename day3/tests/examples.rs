@@ -0,0 +1,22 @@
+use aoc_core::Solver;
+use day3::Day3;
+
+#[test]
+fn part1_example() {
+    let input = Day3.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day3.part1(&input).unwrap(), 4361);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day3.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day3.part2(&input).unwrap(), 467835);
+}
+
+#[test]
+fn numbers_too_large_point_at_their_first_digit() {
+    let Err(err) = Day3.parse("..*.........\n.99999999999\n") else {
+        panic!("a number above u32::MAX parsed");
+    };
+    assert!(err.to_string().starts_with("line 2, column 2: expected a number that fits into u32"), "{}", err);
+}
@@ -0,0 +1,22 @@
+use std::fmt::Write;
+
+use aoc_core::rng::Rng;
+
+// size cards with 10 winning numbers and 25 numbers you have. Most cards win
+// nothing and none wins more than three, which keeps the number of copies in
+// part 2 roughly linear in size. No card wins copies past the end.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    let mut numbers: Vec<u32> = (1..100).collect();
+    for no in 1..=size {
+        let matches = if rng.chance(1, 4) { rng.between(1, 3).min(size - no) } else { 0 };
+        rng.shuffle(&mut numbers);
+        let (winners, rest) = numbers.split_at(10);
+        let mut mine: Vec<u32> = winners[..matches].iter().chain(&rest[..25 - matches]).copied().collect();
+        rng.shuffle(&mut mine);
+
+        let list = |ns: &[u32]| ns.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ");
+        let _ = writeln!(buf, "Card {:>3}: {} | {}", no, list(winners), list(&mine));
+    }
+    buf
+}
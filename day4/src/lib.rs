@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use aoc_core::math::{checked_sum, overflow};
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+fn parse_card(p: &mut Parser) -> aoc_core::Result<Card> {
+    p.literal("Card")?;
+    p.number::<u32>()?;
+    p.literal(":")?;
+    let winners = p.many(|p| p.number::<u32>())?;
+    p.literal("|")?;
+    let candidates = p.many(|p| p.number::<u32>())?;
+    Ok(Card { winners, candidates })
+}
+
+// solves 4.1, None if the score overflows
+fn compute_game_score(winners: &[u32], candidates: &[u32]) -> Option<usize> {
+    let hs: HashSet<u32> = winners.iter().copied().collect();
+    let mut value = 0usize;
+    for c in candidates {
+        if hs.contains(c) {
+            if value == 0 {
+                value = 1;
+            } else {
+                value = value.checked_mul(2)?;
+            }
+        }
+    }
+    Some(value)
+}
+
+fn compute_winners(winners: &[u32], candidates: &[u32]) -> usize {
+    let hs: HashSet<u32> = winners.iter().copied().collect();
+    candidates.iter().filter(|c| hs.contains(c)).count()
+}
+
+// the cards card idx wins, and the cards those win, and so on
+fn spawn(og: &[usize], idx: usize) -> aoc_core::Result<usize> {
+    let m = og[idx];
+    if idx + m >= og.len() {
+        return Err(format!("card {} wins copies of cards past the end of the table", idx + 1).into());
+    }
+    let mut total = m;
+    for i in 1..=m {
+        total = total.checked_add(spawn(og, idx + i)?).ok_or_else(overflow::<usize>)?;
+    }
+    Ok(total)
+}
+
+// Same as adding up spawn() for every card, but each card's copies are
+// counted once, from the back: a card is worth itself plus what the cards it
+// wins are worth.
+fn total_cards(og: &[usize]) -> aoc_core::Result<usize> {
+    let mut worth = vec![1usize; og.len()];
+    for idx in (0..og.len()).rev() {
+        let m = og[idx];
+        if idx + m >= og.len() {
+            return Err(format!("card {} wins copies of cards past the end of the table", idx + 1).into());
+        }
+        worth[idx] = checked_sum(worth[idx..=idx + m].iter().copied())?;
+    }
+    checked_sum(worth)
+}
+
+fn matches(cards: &[Card]) -> Vec<usize> {
+    cards
+        .iter()
+        .map(|card| compute_winners(&card.winners, &card.candidates))
+        .collect()
+}
+
+pub struct Card {
+    winners: Vec<u32>,
+    candidates: Vec<u32>,
+}
+
+#[derive(Default)]
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, |p| p.lines(parse_card))
+    }
+
+    fn part1(&self, cards: &Self::Input) -> aoc_core::Result<usize> {
+        let scores = cards
+            .iter()
+            .map(|card| compute_game_score(&card.winners, &card.candidates).ok_or_else(overflow::<usize>))
+            .collect::<aoc_core::Result<Vec<usize>>>()?;
+        checked_sum(scores)
+    }
+
+    fn part2(&self, cards: &Self::Input) -> aoc_core::Result<usize> {
+        total_cards(&matches(cards))
+    }
+
+    fn reference(&self, part: u32, cards: &Self::Input) -> Option<aoc_core::Result<usize>> {
+        if part != 2 {
+            return None;
+        }
+        let og = matches(cards);
+        let copies = (0..og.len()).map(|card| spawn(&og, card)).collect::<aoc_core::Result<Vec<usize>>>();
+        Some(copies.and_then(|copies| checked_sum(copies.into_iter().chain([og.len()]))))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
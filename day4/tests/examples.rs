@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day4::Day4;
+
+#[test]
+fn part1_example() {
+    let input = Day4.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day4.part1(&input).unwrap(), 13);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day4.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day4.part2(&input).unwrap(), 30);
+}
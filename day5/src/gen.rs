@@ -0,0 +1,51 @@
+use std::fmt::Write;
+
+use aoc_core::rng::Rng;
+
+use crate::MAP_NAMES;
+
+// starts of non overlapping ranges of the given lengths, in ascending order
+fn layout(rng: &mut Rng, lens: &[usize], step: usize) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut cursor = rng.below(step);
+    for len in lens {
+        starts.push(cursor);
+        cursor += len + rng.below(step);
+    }
+    starts
+}
+
+// ten seed ranges and size ranges per map. Like in the real almanac neither
+// the sources nor the destinations of a map overlap. The numbers only use
+// the full 32 bits from a size of 256 on, small almanacs have few enough
+// seeds to try them one by one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let span = size.saturating_pow(4).clamp(1 << 12, 1 << 32);
+    let step = span / (2 * size + 2);
+
+    let seeds: Vec<String> = (0..10)
+        .map(|_| format!("{} {}", rng.below(span / 2), rng.between(1, span / 64)))
+        .collect();
+    let mut buf = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in MAP_NAMES {
+        let lens: Vec<usize> = (0..size).map(|_| rng.between(1, step)).collect();
+        let sources = layout(rng, &lens, step);
+        let mut order: Vec<usize> = (0..size).collect();
+        rng.shuffle(&mut order);
+        let shuffled: Vec<usize> = order.iter().map(|&i| lens[i]).collect();
+        let mut dests = vec![0; size];
+        for (k, start) in layout(rng, &shuffled, step).into_iter().enumerate() {
+            dests[order[k]] = start;
+        }
+
+        let mut lines: Vec<usize> = (0..size).collect();
+        rng.shuffle(&mut lines);
+        let _ = write!(buf, "\n{} map:\n", name);
+        for i in lines {
+            let _ = writeln!(buf, "{} {} {}", dests[i], sources[i], lens[i]);
+        }
+    }
+    buf
+}
@@ -0,0 +1,199 @@
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::session::Session;
+use aoc_core::{trace, Solver};
+
+mod gen;
+mod session;
+
+// (destination start, source start, length)
+type Ranges = Vec<(usize, usize, usize)>;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+#[derive(Clone)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    // in the order of MAP_NAMES
+    maps: Vec<Ranges>,
+}
+
+// the almanac only has 32 bit numbers, reading them as such means start + len
+// can't overflow later on
+fn parse_number(p: &mut Parser) -> aoc_core::Result<usize> {
+    Ok(p.number::<u32>()? as usize)
+}
+
+fn parse_range_list(p: &mut Parser) -> aoc_core::Result<Ranges> {
+    p.lines(|p| Ok((parse_number(p)?, parse_number(p)?, parse_number(p)?)))
+}
+
+fn parse_game(p: &mut Parser) -> aoc_core::Result<Almanac> {
+    let seeds = p.section("seeds", |p| p.many(parse_number))?;
+    let mut maps = Vec::new();
+    for name in MAP_NAMES {
+        p.eol()?;
+        p.skip_blank_lines();
+        maps.push(p.section(&format!("{} map", name), parse_range_list)?);
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+fn map_over_ranges(inp: usize, ranges: &Ranges) -> usize {
+    for (dest, src, len) in ranges {
+        let (dest, src, len) = (*dest, *src, *len);
+        if src <= inp && inp < src + len {
+            let delta = inp - src;
+            return dest + delta;
+        }
+    }
+    inp
+}
+
+// Maps every interval [start, end) through the ranges. The part of an
+// interval that a range covers moves to its destination, whatever no range
+// covers keeps its value.
+fn map_intervals(intervals: &[(usize, usize)], ranges: &Ranges) -> Vec<(usize, usize)> {
+    let mut mapped = Vec::new();
+    let mut todo = intervals.to_vec();
+    for &(dest, src, len) in ranges {
+        let mut rest = Vec::new();
+        for (start, end) in todo {
+            let lo = std::cmp::max(start, src);
+            let hi = std::cmp::min(end, src + len);
+            if lo < hi {
+                mapped.push((lo - src + dest, hi - src + dest));
+                if start < lo {
+                    rest.push((start, lo));
+                }
+                if hi < end {
+                    rest.push((hi, end));
+                }
+            } else {
+                rest.push((start, end));
+            }
+        }
+        todo = rest;
+    }
+    mapped.extend(todo);
+    mapped
+}
+
+// for task 2 the seeds are pairs of start and length
+fn seed_intervals(seeds: &[usize]) -> aoc_core::Result<Vec<(usize, usize)>> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err("seeds must come in pairs of start and length".into());
+    }
+    Ok(seeds.chunks(2).map(|seed| (seed[0], seed[0] + seed[1])).collect())
+}
+
+// task 2 one seed at a time, far too slow for the real input but easy to
+// trust
+fn lowest_location_per_seed(almanac: &Almanac) -> aoc_core::Result<usize> {
+    let min = seed_intervals(&almanac.seeds)?
+        .into_iter()
+        .flat_map(|(start, end)| start..end)
+        .map(|seed| almanac.maps.iter().fold(seed, map_over_ranges))
+        .min();
+
+    min.ok_or_else(|| "no seeds".into())
+}
+
+#[derive(Default)]
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = Almanac;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, parse_game)
+    }
+
+    fn part1(&self, almanac: &Self::Input) -> aoc_core::Result<usize> {
+        let min = almanac.seeds.iter().map(|seed| {
+            let location = almanac.maps.iter().fold(*seed, map_over_ranges);
+            trace!("seed {} ends up at location {}", seed, location);
+            location
+        }).min();
+
+        min.ok_or_else(|| "no seeds".into())
+    }
+
+    fn part2(&self, almanac: &Self::Input) -> aoc_core::Result<usize> {
+        let mut intervals = seed_intervals(&almanac.seeds)?;
+        for (name, ranges) in MAP_NAMES.iter().zip(&almanac.maps) {
+            intervals = map_intervals(&intervals, ranges);
+            trace!("after the {} map: {:?}", name, intervals);
+        }
+        let min = intervals
+            .iter()
+            .filter(|(start, end)| start < end)
+            .map(|(start, _)| *start)
+            .min();
+
+        min.ok_or_else(|| "no seeds".into())
+    }
+
+    fn reference(&self, part: u32, almanac: &Self::Input) -> Option<aoc_core::Result<usize>> {
+        (part == 2).then(|| lowest_location_per_seed(almanac))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+
+    fn session(&self, almanac: &Self::Input) -> Option<Box<dyn Session>> {
+        Some(Box::new(session::Lookup::new(almanac)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Almanac {
+        Day5.parse(include_str!("../tests/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn map_over_ranges_moves_covered_values() {
+        let seed_to_soil = &example().maps[0];
+        assert_eq!(map_over_ranges(79, seed_to_soil), 81);
+        assert_eq!(map_over_ranges(14, seed_to_soil), 14);
+        assert_eq!(map_over_ranges(55, seed_to_soil), 57);
+        assert_eq!(map_over_ranges(13, seed_to_soil), 13);
+        assert_eq!(map_over_ranges(98, seed_to_soil), 50);
+        assert_eq!(map_over_ranges(100, seed_to_soil), 100);
+    }
+
+    #[test]
+    fn map_intervals_splits_at_range_borders() {
+        let seed_to_soil = &example().maps[0];
+        let mut mapped = map_intervals(&[(45, 101)], seed_to_soil);
+        mapped.sort();
+        assert_eq!(mapped, vec![(45, 50), (50, 52), (52, 100), (100, 101)]);
+    }
+
+    #[test]
+    fn per_seed_loop_agrees_with_intervals() {
+        let almanac = example();
+        assert_eq!(lowest_location_per_seed(&almanac).unwrap(), 46);
+        assert_eq!(Day5.part2(&almanac).unwrap(), 46);
+    }
+
+    #[test]
+    fn odd_number_of_seeds_is_an_error() {
+        let mut almanac = example();
+        almanac.seeds.pop();
+        assert!(Day5.part2(&almanac).is_err());
+    }
+}
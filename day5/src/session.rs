@@ -0,0 +1,57 @@
+use aoc_core::session::Session;
+
+use crate::{map_over_ranges, Almanac, MAP_NAMES};
+
+// Looks up numbers in the almanac, following them through every map.
+pub struct Lookup {
+    almanac: Almanac,
+}
+
+// seed, soil, ..., humidity, location
+fn categories() -> Vec<&'static str> {
+    let mut names: Vec<&str> = MAP_NAMES.iter().map(|name| name.split("-to-").next().unwrap_or(name)).collect();
+    names.push(MAP_NAMES[MAP_NAMES.len() - 1].rsplit("-to-").next().unwrap_or("location"));
+    names
+}
+
+impl Lookup {
+    pub fn new(almanac: &Almanac) -> Self {
+        Lookup { almanac: almanac.clone() }
+    }
+
+    // "seed 79, soil 81, ..., location 82"
+    fn query(&self, category: &str, number: &str) -> aoc_core::Result<String> {
+        let names = categories();
+        let from = names.iter().position(|name| *name == category).ok_or_else(|| {
+            format!("unknown category {:?}, expected one of {}", category, names.join(", "))
+        })?;
+        let mut value: usize = number.parse().map_err(|_| format!("not a number: {:?}", number))?;
+        let mut chain = vec![format!("{} {}", category, value)];
+        for (ranges, name) in self.almanac.maps[from..].iter().zip(&names[from + 1..]) {
+            value = map_over_ranges(value, ranges);
+            chain.push(format!("{} {}", name, value));
+        }
+        Ok(chain.join(", "))
+    }
+}
+
+impl Session for Lookup {
+    fn help(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("query <category> <n>", "follow n from its category (seed, soil, ...) to its location"),
+            ("show seeds", "the seed numbers of the almanac"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<aoc_core::Result<String>> {
+        let out = match (command, args) {
+            ("query", [category, number]) => self.query(category, number),
+            ("show", ["seeds"]) => {
+                let seeds: Vec<String> = self.almanac.seeds.iter().map(|seed| seed.to_string()).collect();
+                Ok(seeds.join(" "))
+            }
+            _ => return None,
+        };
+        Some(out)
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_core::Solver;
+use day5::Day5;
+
+#[test]
+fn part1_example() {
+    let input = Day5.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day5.part1(&input).unwrap(), 35);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day5.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day5.part2(&input).unwrap(), 46);
+}
+
+#[test]
+fn session_follows_a_seed_to_its_location() {
+    let input = Day5.parse(include_str!("example.txt")).unwrap();
+    let mut session = Day5.session(&input).unwrap();
+    let out = session.run("query", &["seed", "79"]).unwrap().unwrap();
+    assert_eq!(out, "seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82");
+    assert_eq!(session.run("query", &["humidity", "78"]).unwrap().unwrap(), "humidity 78, location 82");
+    assert!(session.run("query", &["moon", "1"]).unwrap().is_err());
+}
@@ -0,0 +1,144 @@
+use aoc_core::math::checked_product;
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+// t = time button is held
+// L = time limit of the race
+// K = record distance
+// 
+// the distance traveled is D(t) = t * (L - t)
+// 
+// Find all t in 1..L where D(t) - K > 0, so basically solve for t:
+// 
+// 0 = t * (L - t) - K = -t^2 + tL - K 
+// 0 = t^2 - tL + K
+//
+// ==> the basic quadratic equation (abc-formel!!)
+
+fn closed_form(time_limit: usize, record_dist: usize) -> (f64, f64) {
+    let l = time_limit as f64;
+    let k = record_dist as f64;
+    let t1 = (l + (l*l - 4.0 * k).sqrt()) * 0.5;
+    let t2 = (l - (l*l - 4.0 * k).sqrt()) * 0.5;
+    (t1, t2)
+}
+
+// number of integers strictly between the two roots, if a root is an integer
+// itself the boat only ties the record at that point which doesn't count
+fn count_ways(time_limit: usize, record_dist: usize) -> usize {
+    let (t1, t2) = closed_form(time_limit, record_dist);
+    // no real roots, the record is out of reach
+    if t1.is_nan() {
+        return 0;
+    }
+    let hi = t1.ceil() - 1.0;
+    let lo = t2.floor() + 1.0;
+    if hi < lo {
+        return 0;
+    }
+    (hi - lo) as usize + 1
+}
+
+// The same count without floats. Trying every hold time would take far too
+// long for the kerned race, but the distance grows up to half the time limit,
+// so the shortest winning hold time can be searched for instead. The longest
+// one is just as far from the other end.
+fn count_ways_exact(time_limit: usize, record_dist: usize) -> usize {
+    let (l, k) = (time_limit as u128, record_dist as u128);
+    let wins = |t: u128| t * (l - t) > k;
+    let half = l / 2;
+    if !wins(half) {
+        return 0;
+    }
+    let (mut lo, mut hi) = (0, half);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if wins(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    (l - 2 * lo + 1) as usize
+}
+
+// for task 2 the spaces between the numbers are just bad kerning
+fn kerned(ns: &[usize]) -> aoc_core::Result<usize> {
+    let digits: String = ns.iter().map(|n| n.to_string()).collect();
+    parse_all(&digits, |p| p.number())
+}
+
+fn parse_line(p: &mut Parser, label: &str) -> aoc_core::Result<Vec<usize>> {
+    let ns = p.section(label, |p| p.many(|p| p.number()))?;
+    p.eol()?;
+    Ok(ns)
+}
+
+pub struct Races {
+    // (time limit, record distance) per race
+    races: Vec<(usize, usize)>,
+    // the single race if the spaces are ignored
+    kerned: (usize, usize),
+}
+
+#[derive(Default)]
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = Races;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        let (ts, ds) = parse_all(buf, |p| Ok((parse_line(p, "Time")?, parse_line(p, "Distance")?)))?;
+        if ts.is_empty() || ts.len() != ds.len() {
+            return Err(format!("got {} times but {} distances", ts.len(), ds.len()).into());
+        }
+        let kerned = (kerned(&ts)?, kerned(&ds)?);
+        let races = ts.into_iter().zip(ds).collect();
+        Ok(Races { races, kerned })
+    }
+
+    fn part1(&self, races: &Self::Input) -> aoc_core::Result<usize> {
+        checked_product(races.races.iter().map(|&(tl, rd)| count_ways(tl, rd)))
+    }
+
+    fn part2(&self, races: &Self::Input) -> aoc_core::Result<usize> {
+        let (tl, rd) = races.kerned;
+        Ok(count_ways(tl, rd))
+    }
+
+    fn reference(&self, part: u32, races: &Self::Input) -> Option<aoc_core::Result<usize>> {
+        match part {
+            1 => Some(checked_product(races.races.iter().map(|&(tl, rd)| count_ways_exact(tl, rd)))),
+            _ => Some(Ok(count_ways_exact(races.kerned.0, races.kerned.1))),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_ways_per_race() {
+        assert_eq!(count_ways(7, 9), 4);
+        assert_eq!(count_ways(15, 40), 8);
+        // the roots are exactly 10 and 20, holding that long only ties
+        assert_eq!(count_ways(30, 200), 9);
+        assert_eq!(count_ways(5, 100), 0);
+    }
+
+    #[test]
+    fn count_ways_exact_agrees() {
+        for (tl, rd) in [(7, 9), (15, 40), (30, 200), (5, 100), (71530, 940200), (1, 0), (0, 0)] {
+            assert_eq!(count_ways_exact(tl, rd), count_ways(tl, rd), "{} {}", tl, rd);
+        }
+    }
+}
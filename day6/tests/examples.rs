@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day6::Day6;
+
+#[test]
+fn part1_example() {
+    let input = Day6.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day6.part1(&input).unwrap(), 288);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day6.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day6.part2(&input).unwrap(), 71503);
+}
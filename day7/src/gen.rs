@@ -0,0 +1,19 @@
+use std::fmt::Write;
+
+use aoc_core::rng::Rng;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+// size hands with bids up to 1000. Each hand draws from one to five labels so
+// that all kinds of hands show up, not just mostly high cards.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    let mut labels = CARDS;
+    for _ in 0..size {
+        rng.shuffle(&mut labels);
+        let pool = &labels[..rng.between(1, 5)];
+        let cards: String = (0..5).map(|_| *rng.pick(pool)).collect();
+        let _ = writeln!(buf, "{} {}", cards, rng.between(1, 1000));
+    }
+    buf
+}
@@ -0,0 +1,205 @@
+use aoc_core::math::{checked_product, checked_sum};
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::Solver;
+
+mod gen;
+
+struct CardCnt {
+    buckets: [u32; 14], // count occurs of cards, bucket 0 holds the jokers
+    bytepatt: u32, // count face value of hand
+}
+
+fn symidx(sym: char, jokers: bool) -> Option<u32> {
+    let idx = match sym {
+        'A' => 13, 'K' => 12, 'Q' => 11, 'T' => 9,
+        '9' => 8, '8' => 7, '7' => 6, '6' => 5, '5' => 4,
+        '4' => 3, '3' => 2, '2' => 1, 
+        'J' => if jokers { 0 } else { 10 },
+        _ => return None,
+    };
+    Some(idx)
+}
+
+impl CardCnt {
+    fn new(hand: &str, jokers: bool) -> Self {
+        let mut s = Self { buckets: [0; 14], bytepatt: 0 };
+        for c in hand.chars() {
+            let v = symidx(c, jokers).expect("cards are checked by parse_hand");
+            s.buckets[v as usize] += 1;
+            s.bytepatt |= v;
+            s.bytepatt <<= 4;
+        }
+        s
+    }
+
+    fn count_jokers(&self) -> u32 {
+        self.buckets[0]
+    }
+
+    fn is_five_of_a_kind(&self) -> bool {
+        let j = self.count_jokers();
+        let fives = self.buckets[0..].iter().filter(|&&b| b + j == 5).count();
+        fives > 0
+    }
+
+    fn is_four_of_a_kind(&self) -> bool {
+        let j = self.count_jokers();
+        let fours = self.buckets[1..].iter().filter(|&&b| b + j == 4).count();
+        fours > 0
+    }
+
+    fn is_full_house(&self) -> bool {
+        let j = self.count_jokers();
+        let threes = self.buckets[1..].iter().filter(|&&b| b + j == 3).count();
+        let twos = self.buckets[1..].iter().filter(|&&b| b == 2).count();
+
+        (threes == 1) && (twos == 1) && (j == 0) || (threes == 2) && (j == 1)
+    }
+
+    fn is_three_of_a_kind(&self) -> bool {
+        let j = self.count_jokers();
+        let threes = self.buckets[1..].iter().filter(|&&b| b + j == 3).count();
+        threes > 0
+    }
+    
+    // x, y, z, w, J
+    fn is_two_pairs(&self) -> bool {
+        let j = self.count_jokers();
+        let twos = self.buckets[1..].iter().filter(|&&b| b == 2).count();
+        // other cases are irrelevant, with two of a kind and one joker a three of a kind or 
+        // a full house can be made and with two jokers a four of a kind can be made, all of 
+        // them are better choices
+        (twos == 2) && (j == 0)
+    }
+
+    fn is_one_pair(&self) -> bool {
+        let j = self.count_jokers();
+        let twos = self.buckets[1..].iter().filter(|&&b| b + j == 2).count();
+        // again, all other cases yield better hands
+        (twos == 1) && (j == 0) || (twos == 4) && (j == 1)
+    }
+
+    fn value(&self) -> u32 {
+        let mut value = 0;
+        if self.is_five_of_a_kind() {
+            value += 0x6000000;
+        } else if self.is_four_of_a_kind() {
+            value += 0x5000000;
+        } else if self.is_full_house() {
+            value += 0x4000000;
+        } else if self.is_three_of_a_kind() {
+            value += 0x3000000;
+        } else if self.is_two_pairs() {
+            value += 0x2000000;
+        } else if self.is_one_pair() {
+            value += 0x1000000;
+        }
+    
+        // max value for bytepatt is ddddd0 = 14540240
+        value + self.bytepatt
+    }
+}
+
+pub struct Hand {
+    cards: String,
+    bet: u32,
+}
+
+fn total_winnings(hands: &[Hand], jokers: bool) -> aoc_core::Result<u64> {
+    let mut store: Vec<(u32, u32)> = hands
+        .iter()
+        .map(|hand| (CardCnt::new(&hand.cards, jokers).value(), hand.bet))
+        .collect();
+        
+    store.sort_unstable_by(|x, y| {
+        let (xv, _) = x;
+        let (yv, _) = y;
+        xv.cmp(yv)
+    });
+
+    let winnings = store.iter().enumerate().map(|(i, x)| {
+        let (_, bet) = x;
+        checked_product([i as u64 + 1, *bet as u64])
+    }).collect::<aoc_core::Result<Vec<u64>>>()?;
+    checked_sum(winnings)
+}
+
+fn parse_hand(p: &mut Parser) -> aoc_core::Result<Hand> {
+    p.skip_ws();
+    let cards = (0..5)
+        .map(|_| p.token("a card", |c| symidx(c, false).map(|_| c)))
+        .collect::<aoc_core::Result<String>>()?;
+    if !p.rest().starts_with([' ', '\t']) {
+        return Err(p.error("a space after the five cards"));
+    }
+    let bet = p.number::<u32>()?;
+    Ok(Hand { cards, bet })
+}
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input = Vec<Hand>;
+    type Answer = u64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, |p| p.lines(parse_hand))
+    }
+
+    fn part1(&self, hands: &Self::Input) -> aoc_core::Result<u64> {
+        total_winnings(hands, false)
+    }
+
+    fn part2(&self, hands: &Self::Input) -> aoc_core::Result<u64> {
+        total_winnings(hands, true)
+    }
+
+    fn print(&self, hands: &Self::Input) -> Option<String> {
+        Some(hands.iter().map(|hand| format!("{} {}\n", hand.cards, hand.bet)).collect())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(hand: &str, jokers: bool) -> u32 {
+        CardCnt::new(hand, jokers).value() >> 24
+    }
+
+    #[test]
+    fn value_ranks_hand_types() {
+        assert_eq!(kind("AAAAA", false), 6);
+        assert_eq!(kind("AA8AA", false), 5);
+        assert_eq!(kind("23332", false), 4);
+        assert_eq!(kind("TTT98", false), 3);
+        assert_eq!(kind("23432", false), 2);
+        assert_eq!(kind("A23A4", false), 1);
+        assert_eq!(kind("23456", false), 0);
+    }
+
+    #[test]
+    fn value_breaks_ties_card_by_card() {
+        let value = |hand| CardCnt::new(hand, false).value();
+        assert!(value("33332") > value("2AAAA"));
+        assert!(value("77888") > value("77788"));
+        assert!(value("KK677") > value("KTJJT"));
+    }
+
+    #[test]
+    fn jokers_make_the_best_hand_and_rank_lowest() {
+        assert_eq!(kind("QJJQ2", true), 5);
+        assert_eq!(kind("T55J5", true), 5);
+        assert_eq!(kind("KTJJT", true), 5);
+        assert_eq!(kind("JJJJJ", true), 6);
+        assert_eq!(kind("2345J", true), 1);
+        assert_eq!(kind("2233J", true), 4);
+        assert!(CardCnt::new("JKKK2", true).value() < CardCnt::new("QQQQ2", true).value());
+    }
+}
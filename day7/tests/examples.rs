@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day7::Day7;
+
+#[test]
+fn part1_example() {
+    let input = Day7.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day7.part1(&input).unwrap(), 6440);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day7.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day7.part2(&input).unwrap(), 5905);
+}
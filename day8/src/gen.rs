@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use aoc_core::rng::Rng;
+
+const PRIMES: [usize; 13] = [5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+const GHOSTS: usize = 4;
+
+fn letter(i: usize) -> char {
+    (b'A' + i as u8) as char
+}
+
+// Builds the structure the real input has and part 2 relies on, see
+// solve_ghosts: every ghost runs through a cycle of directions.len() * p
+// nodes (p a prime, different for every ghost) whose last node is its only
+// end node. The start node leads into the cycle at the same point as the end
+// node does. Each node of the cycle has a twin with the same branches, taking
+// the wrong direction leads to the twin of the next node, so only the
+// directions decide whether the walk ever sees an end node.
+//
+// size is the number of directions, at most 40 so that there are enough
+// three letter names for all the nodes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let directions: Vec<bool> = (0..size.clamp(1, 40)).map(|_| rng.chance(1, 2)).collect();
+    let d = directions.len();
+
+    // names that neither start nor end a ghost's walk
+    let mut names: Vec<String> = (0..26 * 26 * 24)
+        .map(|i| format!("{}{}{}", letter(i / (26 * 24)), letter(i / 24 % 26), letter(1 + i % 24)))
+        .collect();
+    rng.shuffle(&mut names);
+    let mut names = names.into_iter();
+
+    let mut prefixes: Vec<(usize, usize)> = (1..26 * 26 - 1).map(|i| (i / 26, i % 26)).collect();
+    rng.shuffle(&mut prefixes);
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let mut lines = Vec::new();
+    for ghost in 0..GHOSTS {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            let (a, b) = prefixes[2 * ghost];
+            let (c, e) = prefixes[2 * ghost + 1];
+            (format!("{}{}A", letter(a), letter(b)), format!("{}{}Z", letter(c), letter(e)))
+        };
+
+        let len = d * primes[ghost];
+        let mut on_path: Vec<String> = names.by_ref().take(len - 1).collect();
+        on_path.push(end);
+        let twins: Vec<String> = names.by_ref().take(len).collect();
+
+        // branches of every node that leads to position j
+        let branches = |j: usize| {
+            let (good, bad) = (&on_path[j], &twins[j]);
+            if directions[j % d] { (good, bad) } else { (bad, good) }
+        };
+        for i in 0..len {
+            let (left, right) = branches((i + 1) % len);
+            lines.push(format!("{} = ({}, {})", on_path[i], left, right));
+            lines.push(format!("{} = ({}, {})", twins[i], left, right));
+        }
+        let (left, right) = branches(0);
+        lines.push(format!("{} = ({}, {})", start, left, right));
+    }
+    rng.shuffle(&mut lines);
+
+    let mut buf: String = directions.iter().map(|&left| if left { 'L' } else { 'R' }).collect();
+    buf.push_str("\n\n");
+    for line in lines {
+        let _ = writeln!(buf, "{}", line);
+    }
+    buf
+}
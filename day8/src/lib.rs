@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::math::{lcm, overflow};
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::session::Session;
+use aoc_core::{debug, Solver};
+
+mod gen;
+mod session;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Direction {
+    Left,
+    Right
+}
+
+fn parse_directions(p: &mut Parser) -> aoc_core::Result<Vec<Direction>> {
+    let ds = p.many(|p| p.choice(&[("L", Direction::Left), ("R", Direction::Right)]))?;
+    if ds.is_empty() {
+        return Err(p.error("a direction"));
+    }
+    Ok(ds)
+}
+
+fn parse_branch(p: &mut Parser) -> aoc_core::Result<(String, String, String)> {
+    let start = p.word()?.to_string();
+    p.literal("=")?;
+    p.literal("(")?;
+    let left = p.word()?.to_string();
+    p.literal(",")?;
+    let right = p.word()?.to_string();
+    p.literal(")")?;
+    Ok((start, left, right))
+}
+
+#[derive(Debug, Clone)]
+pub struct GameInfo {
+    branch_map: HashMap<u32, (u32, u32)>,
+    // node label by id
+    labels: Vec<String>,
+    directions: Vec<Direction>,
+    start_nodes: Vec<u32>,
+    end_nodes: HashSet<u32>,
+
+    aaa_id: Option<u32>,
+    zzz_id: Option<u32>,
+}
+
+fn get_id(node_map: &mut HashMap<String, u32>, next_node_id: &mut u32, label: String) -> u32 {
+    let id = node_map.entry(label.clone()).or_insert(*next_node_id);
+    if *id == *next_node_id {
+        *next_node_id += 1;
+    }
+    *id
+}
+
+fn is_start_node(label: &str) -> bool {
+    if let Some(c) = label.chars().nth(2) {
+        return c == 'A';
+    }
+    false
+}
+
+fn is_end_node(label: &str) -> bool {
+    if let Some(c) = label.chars().nth(2) {
+        return c == 'Z';
+    }
+    false
+}
+
+fn parse_game(p: &mut Parser) -> aoc_core::Result<GameInfo> {
+    let directions = parse_directions(p)?;
+    p.eol()?;
+    p.skip_blank_lines();
+    let mut defined = HashSet::new();
+    let branches = p.lines(|p| {
+        // look at the node before parsing the line, so that the error
+        // points at where it is defined the second time
+        if let Ok(label) = Parser::new(p.rest()).word() {
+            if !defined.insert(label) {
+                return Err(p.error(format!("{} to be defined only once", label)));
+            }
+        }
+        parse_branch(p)
+    })?;
+
+    let mut next_node_id = 0u32;
+    let mut node_map: HashMap<String, u32> = HashMap::new();
+    let mut branch_map: HashMap<u32, (u32, u32)> = HashMap::new();
+
+    for (start, left, right) in branches {
+        let sid = get_id(&mut node_map, &mut next_node_id, start.clone());
+        let lid = get_id(&mut node_map, &mut next_node_id, left);
+        let rid = get_id(&mut node_map, &mut next_node_id, right);
+        branch_map.insert(sid, (lid, rid));
+    }
+
+    let mut start_nodes: Vec<u32> = Vec::new();
+    let mut end_nodes: HashSet<u32> = HashSet::new();
+
+    for (label, id) in node_map.iter() {
+        if is_start_node(label) {
+            start_nodes.push(*id);
+        } else if is_end_node(label) {
+            end_nodes.insert(*id);
+        }
+    }
+
+    for (label, id) in node_map.iter() {
+        if !branch_map.contains_key(id) {
+            return Err(format!("node {} is never defined", label).into());
+        }
+    }
+
+    let aaa_id = node_map.get("AAA").copied();
+    let zzz_id = node_map.get("ZZZ").copied();
+
+    let mut labels = vec![String::new(); node_map.len()];
+    for (label, id) in node_map {
+        labels[id as usize] = label;
+    }
+
+    let g = GameInfo { branch_map, labels, directions, start_nodes, end_nodes, aaa_id, zzz_id };
+    Ok(g)
+}
+
+struct GameState<'a> {
+    next_dir: usize,
+    game: &'a GameInfo,
+}
+
+impl<'a> GameState<'a> {
+    fn new(game: &'a GameInfo) -> Self {
+        GameState { 
+            next_dir: 0, 
+            game
+        }
+    }
+
+    fn incr_dir(&mut self) {
+        self.next_dir = (self.next_dir + 1) % self.game.directions.len();
+    }
+
+    // fn is_done(&self) -> bool {
+    //     self.state.iter().all(|id| self.game.end_nodes.contains(&id))
+    // } 
+    
+    // upper bound on the steps of any walk that reaches its goal, after this
+    // many steps some (node, direction) pair has repeated and the walk cycles
+    fn max_steps(&self) -> usize {
+        self.game.branch_map.len() * self.game.directions.len()
+    }
+
+    fn step(&mut self, cur: u32) -> u32 {
+        // parse_game made sure every node has a branch
+        let (left, right) = self.game.branch_map.get(&cur).unwrap();
+        let dir = self.game.directions[self.next_dir];
+        self.incr_dir();
+        match dir {
+            Direction::Left => *left,
+            Direction::Right => *right,
+        }
+    }
+
+    fn find_first_reachable_end_node(&mut self, sid: u32) -> Option<(u32, usize)> {
+        self.next_dir = 0;
+        let mut s = 0;
+        let mut cur = sid;
+        loop {
+            if s == self.max_steps() {
+                return None;
+            }
+            cur = self.step(cur);
+            s += 1;
+            if self.game.end_nodes.contains(&cur) {
+                break;
+            }
+        }
+        Some((cur, s))
+    }
+
+    fn solve_for(&mut self, sid: u32, eid: u32) -> Option<usize> {
+        self.next_dir = 0;
+        let mut s = 0;
+        let mut cur = sid;
+        loop {
+            if s == self.max_steps() {
+                return None;
+            }
+            cur = self.step(cur);
+            s += 1;
+            if cur == eid {
+                break;
+            }
+        }
+        Some(s)
+    }
+}
+
+// This is a strange one, basically for task 2 there is an invariant where the
+// cycle between end-node X to X has the same length as the length of the
+// path of the (one) starting node Y that reaches X.
+//
+// .. maybe I don't get that this is implied in the task description but I found 
+// it through (a lot of) trial and error..
+//
+// So, if the dist between Y and X is n, the cycle between X and X has also
+// dist n.
+//
+// If X(i) is the first reachable end node for starting node Y(i) and n(i)
+// the distance then the least amount of steps required to reach a state
+// where all end nodes are reached at the same time is the least common 
+// divisor of n(1)..n(k) for k starting nodes.
+//
+fn solve_ghosts(gdescr: &GameInfo) -> aoc_core::Result<usize> {
+    if gdescr.start_nodes.is_empty() {
+        return Err("there are no start nodes".into());
+    }
+    let mut steps: Vec<usize> = Vec::new();
+    let mut gstate = GameState::new(gdescr);
+    for sid in gstate.game.start_nodes.iter() {
+        let (eid, s) = gstate
+            .find_first_reachable_end_node(*sid)
+            .ok_or("a ghost never reaches an end node")?;
+        debug!("first reachable end node for {} is {} after {} steps", sid, eid, s);
+        steps.push(s);
+    }
+
+    let lcm = steps
+        .iter()
+        .try_fold(1, |acc, s| lcm(acc, *s))
+        .ok_or_else(overflow::<usize>)?;
+    debug!("least common multiple of {:?} is {}", steps, lcm);
+
+    Ok(lcm)
+}
+
+#[derive(Default)]
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = GameInfo;
+    type Answer = usize;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_all(buf, parse_game)
+    }
+
+    fn part1(&self, gdescr: &Self::Input) -> aoc_core::Result<usize> {
+        let aaa_id = gdescr.aaa_id.ok_or("there is no node AAA")?;
+        let zzz_id = gdescr.zzz_id.ok_or("there is no node ZZZ")?;
+        let mut gstate = GameState::new(gdescr);
+        gstate.solve_for(aaa_id, zzz_id).ok_or_else(|| "ZZZ can't be reached from AAA".into())
+    }
+
+    fn part2(&self, gdescr: &Self::Input) -> aoc_core::Result<usize> {
+        solve_ghosts(gdescr)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+
+    fn session(&self, gdescr: &Self::Input) -> Option<Box<dyn Session>> {
+        Some(Box::new(session::Walk::new(gdescr)))
+    }
+}
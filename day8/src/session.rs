@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use aoc_core::session::{count_arg, Session};
+
+use crate::{GameInfo, GameState};
+
+// A walk through the map, one direction at a time.
+pub struct Walk {
+    game: GameInfo,
+    // None if the map has no nodes at all
+    at: Option<u32>,
+    next_dir: usize,
+    steps: usize,
+}
+
+impl Walk {
+    // starts at AAA, or at the first ghost start node if there is no AAA
+    pub fn new(game: &GameInfo) -> Self {
+        let ghost = game.start_nodes.iter().min_by_key(|id| &game.labels[**id as usize]).copied();
+        let at = game.aaa_id.or(ghost).or((!game.labels.is_empty()).then_some(0));
+        Walk { game: game.clone(), at, next_dir: 0, steps: 0 }
+    }
+
+    fn node(&self, label: &str) -> aoc_core::Result<u32> {
+        let id = self.game.labels.iter().position(|l| l == label).ok_or_else(|| format!("no node {}", label))?;
+        Ok(id as u32)
+    }
+
+    fn status(&self) -> aoc_core::Result<String> {
+        let at = self.at.ok_or("the map has no nodes")?;
+        let end = if self.game.end_nodes.contains(&at) || Some(at) == self.game.zzz_id { " (an end node)" } else { "" };
+        let dir = format!("{:?}", self.game.directions[self.next_dir]).to_lowercase();
+        Ok(format!(
+            "at {}{} after {} steps, going {} next",
+            self.game.labels[at as usize], end, self.steps, dir
+        ))
+    }
+
+    fn step(&mut self, n: usize) -> aoc_core::Result<String> {
+        let mut at = self.at.ok_or("the map has no nodes")?;
+        let mut state = GameState { next_dir: self.next_dir, game: &self.game };
+        // sooner or later the walk is back at a node with the same direction
+        // next, from there on it goes round in circles that can be skipped
+        let mut seen = HashMap::new();
+        let mut done = 0;
+        while done < n {
+            if let Some(prev) = seen.insert((at, state.next_dir), done) {
+                done += (n - done) / (done - prev) * (done - prev);
+                break;
+            }
+            at = state.step(at);
+            done += 1;
+        }
+        for _ in done..n {
+            at = state.step(at);
+        }
+        self.next_dir = state.next_dir;
+        self.at = Some(at);
+        self.steps += n;
+        self.status()
+    }
+}
+
+impl Session for Walk {
+    fn help(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("show", "where the walk is"),
+            ("step [<n>]", "walk n steps, 1 by default"),
+            ("reset [<node>]", "start over at AAA or the given node"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<aoc_core::Result<String>> {
+        let out = match (command, args) {
+            ("show", []) => self.status(),
+            ("step", args) => count_arg(args, 1).and_then(|n| self.step(n)),
+            ("reset", []) => {
+                *self = Walk::new(&self.game);
+                self.status()
+            }
+            ("reset", [label]) => self.node(label).and_then(|id| {
+                *self = Walk::new(&self.game);
+                self.at = Some(id);
+                self.status()
+            }),
+            _ => return None,
+        };
+        Some(out)
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_core::Solver;
+use day8::Day8;
+
+#[test]
+fn part1_examples() {
+    let input = Day8.parse(include_str!("example1.txt")).unwrap();
+    assert_eq!(Day8.part1(&input).unwrap(), 2);
+    let input = Day8.parse(include_str!("example2.txt")).unwrap();
+    assert_eq!(Day8.part1(&input).unwrap(), 6);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day8.parse(include_str!("example3.txt")).unwrap();
+    assert_eq!(Day8.part2(&input).unwrap(), 6);
+}
+
+#[test]
+fn part1_needs_aaa() {
+    let input = Day8.parse(include_str!("example3.txt")).unwrap();
+    assert!(Day8.part1(&input).is_err());
+}
+
+#[test]
+fn nodes_are_defined_once() {
+    let err = Day8.parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nAAA = (BBB, AAA)\n").unwrap_err();
+    assert!(err.to_string().starts_with("line 5, column 1: expected AAA to be defined only once"), "{}", err);
+}
+
+#[test]
+fn session_walks_step_by_step() {
+    let input = Day8.parse(include_str!("example2.txt")).unwrap();
+    let mut session = Day8.session(&input).unwrap();
+    assert_eq!(session.run("show", &[]).unwrap().unwrap(), "at AAA after 0 steps, going left next");
+    let out = session.run("step", &["6"]).unwrap().unwrap();
+    assert_eq!(out, "at ZZZ (an end node) after 6 steps, going left next");
+    let out = session.run("reset", &["BBB"]).unwrap().unwrap();
+    assert_eq!(out, "at BBB after 0 steps, going left next");
+    assert!(session.run("step", &["x"]).unwrap().is_err());
+    let out = session.run("step", &["1000000000000"]).unwrap().unwrap();
+    assert_eq!(out, "at ZZZ (an end node) after 1000000000000 steps, going left next");
+}
@@ -0,0 +1,16 @@
+use aoc_core::rng::Rng;
+
+// size rows of 21 values of a polynomial of degree at most 6, so the
+// differences always reach a row of zeros before running out of values
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut buf = String::new();
+    for _ in 0..size {
+        let coeffs: Vec<i64> = (0..=rng.below(7)).map(|_| rng.between(0, 18) as i64 - 9).collect();
+        let values: Vec<String> = (0..21i64)
+            .map(|x| coeffs.iter().rev().fold(0, |acc, c| acc * x + c).to_string())
+            .collect();
+        buf.push_str(&values.join(" "));
+        buf.push('\n');
+    }
+    buf
+}
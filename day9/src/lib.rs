@@ -0,0 +1,143 @@
+use aoc_core::math::{checked_sum, overflow};
+use aoc_core::parse::{parse_all, Parser};
+use aoc_core::rng::Rng;
+use aoc_core::{trace, Solver};
+
+mod gen;
+
+fn parse_line(p: &mut Parser) -> aoc_core::Result<Vec<i64>> {
+    p.many(|p| p.number::<i64>())
+}
+
+fn parse_inp(xs: &str) -> aoc_core::Result<Vec<Vec<i64>>> {
+    parse_all(xs, |p| p.lines(parse_line))
+}
+
+#[derive(Debug)]
+struct Diffs {
+    diffs: Vec<Vec<i64>>,
+    last_row: usize,
+    // row_sums: Vec<i64>,
+}
+
+impl Diffs {
+    // None if a difference overflows
+    fn calc(row0: &[i64]) -> Option<Self> {
+        let n = row0.len();
+        let mut diffs = vec![vec![0; n]; n + 1];
+
+        let mut last_row = 0;
+
+        for j in 0..n {
+            diffs[0][j] = row0[j];
+            if diffs[0][j] != 0 {
+                last_row = 1;
+            }
+        }
+
+        for i in 1..n {
+            for j in (i - 1)..(n - 1) {
+                let d_next = diffs[i - 1][j + 1];
+                let d_prev = diffs[i - 1][j];
+                diffs[i][j + 1] = d_next.checked_sub(d_prev)?;
+                if diffs[i][j + 1] != 0 {
+                    last_row = i;
+                }
+            }
+        }
+        Some(Self { diffs, last_row })
+    }
+
+    fn solve_row_forward(&self, i: usize, diff: i64) -> Option<i64> {
+        let d_prev = *self.diffs[i].last()?;
+         
+        diff.checked_add(d_prev)
+    }
+
+    fn solve_forward(&self) -> Option<i64> {
+        let istart = self.last_row;
+        let mut value = 0;
+        for i in (0..=istart).rev() {
+            value = self.solve_row_forward(i, value)?;
+        }
+        Some(value)
+    }
+}
+
+fn extrapolate(data: &[Vec<i64>], backwards: bool) -> aoc_core::Result<i64> {
+    let mut values = Vec::new();
+    for row in data {
+        let row: Vec<i64> = if backwards {
+            row.iter().rev().copied().collect()
+        } else {
+            row.clone()
+        };
+        let diffs = Diffs::calc(&row).ok_or_else(overflow::<i64>)?;
+        for dr in &diffs.diffs[..=diffs.last_row] {
+            trace!("{:?}", dr);
+        }
+        let val = diffs.solve_forward().ok_or_else(overflow::<i64>)?;
+        trace!("next value is {}", val);
+        values.push(val);
+    }
+    checked_sum(values)
+}
+
+#[derive(Default)]
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(&self, buf: &str) -> aoc_core::Result<Self::Input> {
+        parse_inp(buf)
+    }
+
+    fn part1(&self, data: &Self::Input) -> aoc_core::Result<i64> {
+        extrapolate(data, false)
+    }
+
+    fn part2(&self, data: &Self::Input) -> aoc_core::Result<i64> {
+        extrapolate(data, true)
+    }
+
+    fn print(&self, data: &Self::Input) -> Option<String> {
+        let rows = data.iter().map(|row| {
+            let values: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            values.join(" ") + "\n"
+        });
+        Some(rows.collect())
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> aoc_core::Result<String> {
+        Ok(gen::generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forward(row: &[i64]) -> i64 {
+        Diffs::calc(row).unwrap().solve_forward().unwrap()
+    }
+
+    #[test]
+    fn solve_forward_extrapolates_the_next_value() {
+        assert_eq!(forward(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(forward(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(forward(&[10, 13, 16, 21, 30, 45]), 68);
+    }
+
+    #[test]
+    fn solve_forward_on_a_reversed_row_extrapolates_backwards() {
+        assert_eq!(forward(&[45, 30, 21, 16, 13, 10]), 5);
+        assert_eq!(forward(&[7, 7, 7]), 7);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert!(Diffs::calc(&[i64::MIN, i64::MAX]).is_none());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_core::Solver;
+use day9::Day9;
+
+#[test]
+fn part1_example() {
+    let input = Day9.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day9.part1(&input).unwrap(), 114);
+}
+
+#[test]
+fn part2_example() {
+    let input = Day9.parse(include_str!("example.txt")).unwrap();
+    assert_eq!(Day9.part2(&input).unwrap(), 2);
+}